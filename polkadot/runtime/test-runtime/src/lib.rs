@@ -348,7 +348,12 @@ impl pallet_staking::Config for Runtime {
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
+	type SlashDeferThreshold = ();
 	type AdminOrigin = frame_system::EnsureNever<()>;
+	type PayeeControlOrigin = pallet_staking::EnsureSignedController;
+	type PayeeChangeDelay = ();
+	type PendingPayeeScanLimit = ConstU32<64>;
+	type EscrowBondOrigin = pallet_staking::EnsureSignedController;
 	type SessionInterface = Self;
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
 	type MaxExposurePageSize = MaxExposurePageSize;
@@ -361,12 +366,29 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = pallet_staking::UseValidatorsMap<Runtime>;
 	type NominationsQuota = pallet_staking::FixedNominationsQuota<MAX_QUOTA_NOMINATIONS>;
 	type MaxUnlockingChunks = frame_support::traits::ConstU32<32>;
+	type AutoChillOnUnbond = frame_support::traits::ConstBool<false>;
+	type ChillOtherRequiresCountLimit = frame_support::traits::ConstBool<true>;
+	type MaxChillOtherBatch = ConstU32<64>;
+	type MaxForceUnstakeBatch = frame_support::traits::ConstU32<64>;
+	type MaxPayoutBatch = frame_support::traits::ConstU32<16>;
+	type MaxLedgerTotal = ();
+	type BondEdBuffer = ();
+	type MinEventedUnbond = ();
+	type IdleReapEras = ();
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type NominationExpiryScanLimit = ConstU32<64>;
+	type AutoPayout = frame_support::traits::ConstBool<false>;
+	type MaxAutoPayoutsPerBlock = ConstU32<16>;
 	type MaxControllersInDeprecationBatch = ConstU32<5900>;
 	type HistoryDepth = frame_support::traits::ConstU32<84>;
 	type BenchmarkingConfig = polkadot_runtime_common::StakingBenchmarkingConfig;
 	type EventListeners = ();
+	type OnEraStart = ();
+	type OnEraEnd = ();
 	type WeightInfo = ();
 	type DisablingStrategy = pallet_staking::UpToLimitDisablingStrategy;
+	type OffenceExemption = ();
+	type PoolInterface = ();
 }
 
 parameter_types! {
@@ -630,7 +630,12 @@ impl pallet_staking::Config for Runtime {
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
+	type SlashDeferThreshold = ();
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
+	type PayeeControlOrigin = pallet_staking::EnsureSignedController;
+	type PayeeChangeDelay = ();
+	type PendingPayeeScanLimit = ConstU32<64>;
+	type EscrowBondOrigin = pallet_staking::EnsureSignedController;
 	type SessionInterface = Self;
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
 	type MaxExposurePageSize = MaxExposurePageSize;
@@ -641,12 +646,29 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = UseValidatorsMap<Self>;
 	type NominationsQuota = pallet_staking::FixedNominationsQuota<{ MaxNominations::get() }>;
 	type MaxUnlockingChunks = frame_support::traits::ConstU32<32>;
+	type AutoChillOnUnbond = frame_support::traits::ConstBool<false>;
+	type ChillOtherRequiresCountLimit = frame_support::traits::ConstBool<true>;
+	type MaxChillOtherBatch = frame_support::traits::ConstU32<64>;
+	type MaxForceUnstakeBatch = frame_support::traits::ConstU32<64>;
+	type MaxPayoutBatch = frame_support::traits::ConstU32<16>;
+	type MaxLedgerTotal = ();
+	type BondEdBuffer = ();
+	type MinEventedUnbond = ();
+	type IdleReapEras = ();
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type NominationExpiryScanLimit = ConstU32<64>;
+	type AutoPayout = frame_support::traits::ConstBool<false>;
+	type MaxAutoPayoutsPerBlock = frame_support::traits::ConstU32<16>;
 	type HistoryDepth = frame_support::traits::ConstU32<84>;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
 	type BenchmarkingConfig = polkadot_runtime_common::StakingBenchmarkingConfig;
 	type EventListeners = (NominationPools, DelegatedStaking);
+	type OnEraStart = ();
+	type OnEraEnd = ();
 	type WeightInfo = weights::pallet_staking::WeightInfo<Runtime>;
 	type DisablingStrategy = pallet_staking::UpToLimitDisablingStrategy;
+	type OffenceExemption = ();
+	type PoolInterface = ();
 }
 
 impl pallet_fast_unstake::Config for Runtime {
@@ -1678,6 +1700,8 @@ pub mod migrations {
 			MaxPoolsToMigrate,
 		>,
 		pallet_staking::migrations::v15::MigrateV14ToV15<Runtime>,
+		pallet_staking::migrations::v16::MigrateV15ToV16<Runtime>,
+		pallet_staking::migrations::v17::MigrateV16ToV17<Runtime>,
 	);
 }
 
@@ -2311,6 +2335,111 @@ sp_api::impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn era_summary(era: sp_staking::EraIndex) -> Option<sp_staking::EraSummary<Balance>> {
+			Staking::api_era_summary(era)
+		}
+
+		fn next_payout_era(account: AccountId) -> Option<sp_staking::EraIndex> {
+			Staking::api_next_payout_era(account)
+		}
+
+		fn validator_count_status() -> (u32, u32, u32, u32) {
+			Staking::api_validator_count_status()
+		}
+
+		fn exposure_page_of_nominator(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+			nominator: AccountId,
+		) -> Option<sp_staking::Page> {
+			Staking::api_exposure_page_of_nominator(era, validator, nominator)
+		}
+
+		fn total_rewardable(era: sp_staking::EraIndex, account: AccountId) -> Balance {
+			Staking::api_total_rewardable(era, account)
+		}
+
+		fn restricted_accounts() -> Vec<AccountId> {
+			Staking::api_restricted_accounts()
+		}
+
+		fn approval_stake(validator: AccountId) -> Balance {
+			Staking::api_approval_stake(validator)
+		}
+
+		fn validator_count() -> u32 {
+			Staking::api_validator_count()
+		}
+
+		fn nominator_count() -> u32 {
+			Staking::api_nominator_count()
+		}
+
+		fn slash_history(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_slash_history(stash)
+		}
+
+		fn reward_points_leaderboard(era: sp_staking::EraIndex) -> Vec<(AccountId, u32)> {
+			Staking::api_reward_points_leaderboard(era)
+		}
+
+		fn pending_payout_eras(account: AccountId) -> Vec<sp_staking::EraIndex> {
+			Staking::api_pending_payout_eras(account)
+		}
+
+		fn minimum_bond(role: sp_staking::StakerRole) -> Balance {
+			Staking::api_minimum_bond(role)
+		}
+
+		fn ledger_integrity(stash: AccountId) -> Option<sp_staking::LedgerIntegrityState> {
+			Staking::api_ledger_integrity(stash)
+		}
+
+		fn exposure_delta(
+			era_a: sp_staking::EraIndex,
+			era_b: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_staking::ExposureDelta<Balance>> {
+			Staking::api_exposure_delta(era_a, era_b, validator)
+		}
+
+		fn apy_estimate(validator: AccountId) -> Option<sp_runtime::Perbill> {
+			Staking::api_apy_estimate(validator)
+		}
+
+		fn chilled_stakers() -> Vec<AccountId> {
+			Staking::api_chilled_stakers()
+		}
+
+		fn pending_rewards_for(who: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_pending_rewards_for(who)
+		}
+
+		fn validator_backers(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Vec<(AccountId, Balance)> {
+			Staking::api_validator_backers(era, validator)
+		}
+
+		fn election_candidates() -> Vec<AccountId> {
+			Staking::api_election_candidates()
+		}
+
+		fn era_points_for(
+			era: sp_staking::EraIndex,
+			validators: Vec<AccountId>,
+		) -> Vec<(AccountId, u32)> {
+			Staking::api_era_points_for(era, validators)
+		}
+
+		fn full_exposure(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_staking::Exposure<AccountId, Balance>> {
+			Staking::api_full_exposure(era, validator)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
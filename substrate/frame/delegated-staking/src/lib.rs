@@ -488,8 +488,11 @@ impl<T: Config> Pallet<T> {
 		// Get current stake
 		let stake = T::CoreStaking::stake(who)?;
 
+		// virtual stakers cannot be paid out to themselves, so update payee before migrating.
+		T::CoreStaking::update_payee(who, reward_account)?;
+
 		// release funds from core staking.
-		T::CoreStaking::migrate_to_virtual_staker(who);
+		T::CoreStaking::migrate_to_virtual_staker(who)?;
 
 		// transfer just released staked amount plus any free amount.
 		let amount_to_transfer =
@@ -503,7 +506,6 @@ impl<T: Config> Pallet<T> {
 			Preservation::Expendable,
 		)?;
 
-		T::CoreStaking::update_payee(who, reward_account)?;
 		// delegate all transferred funds back to agent.
 		Self::do_delegate(proxy_delegator, Agent::from(who.clone()), amount_to_transfer)?;
 
@@ -533,7 +535,7 @@ impl<T: Config> Pallet<T> {
 		defensive_assert!(amount == available_to_bond, "not expected value to bond");
 
 		if agent_ledger.is_bonded() {
-			T::CoreStaking::bond_extra(&agent_ledger.key, amount)
+			T::CoreStaking::virtual_bond_extra(&agent_ledger.key, amount)
 		} else {
 			T::CoreStaking::virtual_bond(&agent_ledger.key, amount, agent_ledger.reward_account())
 		}
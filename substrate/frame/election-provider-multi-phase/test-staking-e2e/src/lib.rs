@@ -235,7 +235,7 @@ fn continuous_slashes_below_offending_threshold() {
 ///
 /// Related to <https://github.com/paritytech/substrate/issues/14246>.
 fn ledger_consistency_active_balance_below_ed() {
-	use pallet_staking::{Error, Event};
+	use pallet_staking::{ChillRole, Error, Event};
 
 	let (ext, pool_state, _) =
 		ExtBuilder::default().staking(StakingExtBuilder::default()).build_offchainify();
@@ -277,7 +277,17 @@ fn ledger_consistency_active_balance_below_ed() {
 		// check the events so far: 1x Chilled and 1x Unbounded
 		assert_eq!(
 			staking_events(),
-			[Event::Chilled { stash: 11 }, Event::Unbonded { stash: 11, amount: 1000 }]
+			[
+				Event::Chilled { stash: 11 },
+				Event::ChilledWithRole { stash: 11, role: ChillRole::WasValidator },
+				Event::Unbonded {
+					stash: 11,
+					amount: 1000,
+					remaining_active: 0,
+					withdraw_era: current_era() +
+						<Runtime as pallet_staking::Config>::BondingDuration::get(),
+				}
+			]
 		);
 
 		// after advancing `BondingDuration` eras, the `withdraw_unbonded` will unlock the
@@ -384,7 +394,12 @@ fn automatic_unbonding_pools() {
 				// auto-withdraw happened as expected to release 2's unbonding funds, but the funds
 				// were not transferred to 2 and stay in the pool's transferrable balance instead.
 				pallet_staking::Event::Withdrawn { stash: 7939698191839293293, amount: 10 },
-				pallet_staking::Event::Unbonded { stash: 7939698191839293293, amount: 10 }
+				pallet_staking::Event::Unbonded {
+					stash: 7939698191839293293,
+					amount: 10,
+					remaining_active: 5,
+					withdraw_era: 5
+				}
 			]
 		);
 
@@ -215,6 +215,10 @@ impl sp_staking::StakingInterface for StakingMock {
 		unimplemented!("method currently not used in testing")
 	}
 
+	fn total_stake_in_era(_era: EraIndex) -> Option<Self::Balance> {
+		unimplemented!("method currently not used in testing")
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn add_era_stakers(
 		_current_era: &EraIndex,
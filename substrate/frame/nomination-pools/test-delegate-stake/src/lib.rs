@@ -29,7 +29,7 @@ use pallet_nomination_pools::{
 	PoolMembers, PoolState,
 };
 use pallet_staking::{
-	CurrentEra, Error as StakingError, Event as StakingEvent, Payee, RewardDestination,
+	ChillRole, CurrentEra, Error as StakingError, Event as StakingEvent, Payee, RewardDestination,
 };
 
 use pallet_delegated_staking::{Error as DelegatedStakingError, Event as DelegatedStakingEvent};
@@ -52,7 +52,10 @@ fn pool_lifecycle_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -69,8 +72,8 @@ fn pool_lifecycle_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 70 },
 			]
 		);
 		assert_eq!(
@@ -102,8 +105,18 @@ fn pool_lifecycle_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 60,
+					withdraw_era: 3
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 50,
+					withdraw_era: 3
+				},
 			]
 		);
 		assert_eq!(
@@ -172,7 +185,17 @@ fn pool_lifecycle_e2e() {
 			staking_events_since_last_call(),
 			vec![
 				StakingEvent::Chilled { stash: POOL1_BONDED },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 50 },
+				StakingEvent::ChilledWithRole {
+					stash: POOL1_BONDED,
+					role: ChillRole::WasNominator
+				},
+				StakingEvent::NominationsCleared { nominator: POOL1_BONDED, had_targets: 3 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 50,
+					remaining_active: 0,
+					withdraw_era: 6
+				},
 			]
 		);
 		assert_eq!(
@@ -215,7 +238,10 @@ fn pool_chill_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -232,8 +258,8 @@ fn pool_chill_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 70 },
 			]
 		);
 		assert_eq!(
@@ -314,11 +340,28 @@ fn pool_chill_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 60,
+					withdraw_era: 3
+				},
 				StakingEvent::Chilled { stash: POOL1_BONDED },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 }, // other member bonding
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 }, // depositor bond extra
+				StakingEvent::ChilledWithRole {
+					stash: POOL1_BONDED,
+					role: ChillRole::WasNominator
+				},
+				StakingEvent::NominationsCleared { nominator: POOL1_BONDED, had_targets: 3 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 50,
+					withdraw_era: 3
+				},
+				// other member bonding
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 60 },
+				// depositor bond extra
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 70 },
 				StakingEvent::Withdrawn { stash: POOL1_BONDED, amount: 20 },
 			]
 		);
@@ -338,7 +381,10 @@ fn pool_slash_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -360,8 +406,8 @@ fn pool_slash_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 20 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 20 }
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 20, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 20, new_active: 80 }
 			]
 		);
 		assert_eq!(
@@ -382,8 +428,18 @@ fn pool_slash_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 }
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 70,
+					withdraw_era: 4
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 60,
+					withdraw_era: 4
+				}
 			]
 		);
 		assert_eq!(
@@ -405,9 +461,24 @@ fn pool_slash_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 50,
+					withdraw_era: 5
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 40,
+					withdraw_era: 5
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 30,
+					withdraw_era: 5
+				},
 			]
 		);
 
@@ -432,7 +503,12 @@ fn pool_slash_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 30 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 30,
+				slash_era: 2,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -459,7 +535,12 @@ fn pool_slash_e2e() {
 		);
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 5 }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: 5,
+				remaining_active: 10,
+				withdraw_era: 6
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -494,7 +575,12 @@ fn pool_slash_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: 10,
+				remaining_active: 0,
+				withdraw_era: 9
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -548,7 +634,10 @@ fn pool_slash_proportional() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			delegated_staking_events_since_last_call(),
@@ -575,9 +664,9 @@ fn pool_slash_proportional() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 80 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 100 },
 			]
 		);
 		assert_eq!(
@@ -617,7 +706,12 @@ fn pool_slash_proportional() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond },]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 80,
+				withdraw_era: 127
+			},]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -634,7 +728,12 @@ fn pool_slash_proportional() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(21), 21, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond },]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 60,
+				withdraw_era: 128
+			},]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -651,7 +750,12 @@ fn pool_slash_proportional() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(22), 22, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond },]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 40,
+				withdraw_era: 129
+			},]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -684,7 +788,12 @@ fn pool_slash_proportional() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 50 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 50,
+				slash_era: 100,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -764,7 +873,10 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 40, 10, 10, 10));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -779,7 +891,7 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 		assert_ok!(Pools::join(RuntimeOrigin::signed(20), bond, 1));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 60 }]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -791,7 +903,12 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 40,
+				withdraw_era: 127
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -817,7 +934,12 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 30 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 30,
+				slash_era: 100,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -843,7 +965,10 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 40, 10, 10, 10));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -858,7 +983,7 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		assert_ok!(Pools::join(RuntimeOrigin::signed(20), bond, 1));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 60 }]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -870,7 +995,12 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 40,
+				withdraw_era: 127
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -896,7 +1026,12 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 50 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 50,
+				slash_era: 100,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -925,7 +1060,10 @@ fn pool_migration_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -951,9 +1089,9 @@ fn pool_migration_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 70 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 80 },
 			]
 		);
 		assert_eq!(
@@ -976,8 +1114,18 @@ fn pool_migration_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 5 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 5,
+					remaining_active: 75,
+					withdraw_era: 5
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 65,
+					withdraw_era: 6
+				},
 			]
 		);
 		assert_eq!(
@@ -1061,7 +1209,17 @@ fn pool_migration_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 5 },
+				// the payee is updated when migrating the pool.
+				StakingEvent::PayeeSet {
+					stash: POOL1_BONDED,
+					payee: RewardDestination::Account(POOL1_REWARD)
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 5,
+					remaining_active: 60,
+					withdraw_era: 8
+				},
 				StakingEvent::Withdrawn { stash: POOL1_BONDED, amount: 5 }
 			]
 		);
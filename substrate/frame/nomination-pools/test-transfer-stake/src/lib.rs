@@ -26,7 +26,7 @@ use pallet_nomination_pools::{
 	PoolMembers, PoolState,
 };
 use pallet_staking::{
-	CurrentEra, Error as StakingError, Event as StakingEvent, Payee, RewardDestination,
+	ChillRole, CurrentEra, Error as StakingError, Event as StakingEvent, Payee, RewardDestination,
 };
 use sp_runtime::{bounded_btree_map, traits::Zero};
 
@@ -45,7 +45,10 @@ fn pool_lifecycle_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -62,8 +65,8 @@ fn pool_lifecycle_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 70 },
 			]
 		);
 		assert_eq!(
@@ -95,8 +98,18 @@ fn pool_lifecycle_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 60,
+					withdraw_era: 3
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 50,
+					withdraw_era: 3
+				},
 			]
 		);
 		assert_eq!(
@@ -165,7 +178,17 @@ fn pool_lifecycle_e2e() {
 			staking_events_since_last_call(),
 			vec![
 				StakingEvent::Chilled { stash: POOL1_BONDED },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 50 },
+				StakingEvent::ChilledWithRole {
+					stash: POOL1_BONDED,
+					role: ChillRole::WasNominator
+				},
+				StakingEvent::NominationsCleared { nominator: POOL1_BONDED, had_targets: 3 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 50,
+					remaining_active: 0,
+					withdraw_era: 6
+				},
 			]
 		);
 		assert_eq!(
@@ -213,7 +236,10 @@ fn destroy_pool_with_erroneous_consumer() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -247,7 +273,17 @@ fn destroy_pool_with_erroneous_consumer() {
 			staking_events_since_last_call(),
 			vec![
 				StakingEvent::Chilled { stash: POOL1_BONDED },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 50 },
+				StakingEvent::ChilledWithRole {
+					stash: POOL1_BONDED,
+					role: ChillRole::WasNominator
+				},
+				StakingEvent::NominationsCleared { nominator: POOL1_BONDED, had_targets: 3 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 50,
+					remaining_active: 0,
+					withdraw_era: 4
+				},
 			]
 		);
 		assert_eq!(
@@ -297,7 +333,10 @@ fn pool_chill_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 50 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -314,8 +353,8 @@ fn pool_chill_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 70 },
 			]
 		);
 		assert_eq!(
@@ -396,11 +435,28 @@ fn pool_chill_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 60,
+					withdraw_era: 3
+				},
 				StakingEvent::Chilled { stash: POOL1_BONDED },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 }, // other member bonding
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 10 }, // depositor bond extra
+				StakingEvent::ChilledWithRole {
+					stash: POOL1_BONDED,
+					role: ChillRole::WasNominator
+				},
+				StakingEvent::NominationsCleared { nominator: POOL1_BONDED, had_targets: 3 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 50,
+					withdraw_era: 3
+				},
+				// other member bonding
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 60 },
+				// depositor bond extra
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 10, new_active: 70 },
 				StakingEvent::Withdrawn { stash: POOL1_BONDED, amount: 20 },
 			]
 		);
@@ -420,7 +476,10 @@ fn pool_slash_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -442,8 +501,8 @@ fn pool_slash_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 20 },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 20 }
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 20, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: 20, new_active: 80 }
 			]
 		);
 		assert_eq!(
@@ -464,8 +523,18 @@ fn pool_slash_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 }
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 70,
+					withdraw_era: 4
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 60,
+					withdraw_era: 4
+				}
 			]
 		);
 		assert_eq!(
@@ -487,9 +556,24 @@ fn pool_slash_e2e() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
-				StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 },
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 50,
+					withdraw_era: 5
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 40,
+					withdraw_era: 5
+				},
+				StakingEvent::Unbonded {
+					stash: POOL1_BONDED,
+					amount: 10,
+					remaining_active: 30,
+					withdraw_era: 5
+				},
 			]
 		);
 
@@ -514,7 +598,12 @@ fn pool_slash_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 30 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 30,
+				slash_era: 2,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -541,7 +630,12 @@ fn pool_slash_e2e() {
 		);
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 5 }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: 5,
+				remaining_active: 10,
+				withdraw_era: 6
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -576,7 +670,12 @@ fn pool_slash_e2e() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: 10 }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: 10,
+				remaining_active: 0,
+				withdraw_era: 9
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -630,7 +729,10 @@ fn pool_slash_proportional() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -649,9 +751,9 @@ fn pool_slash_proportional() {
 		assert_eq!(
 			staking_events_since_last_call(),
 			vec![
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond },
-				StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 60 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 80 },
+				StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 100 },
 			]
 		);
 		assert_eq!(
@@ -671,7 +773,12 @@ fn pool_slash_proportional() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond },]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 80,
+				withdraw_era: 127
+			},]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -688,7 +795,12 @@ fn pool_slash_proportional() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(21), 21, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond },]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 60,
+				withdraw_era: 128
+			},]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -705,7 +817,12 @@ fn pool_slash_proportional() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(22), 22, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond },]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 40,
+				withdraw_era: 129
+			},]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -731,7 +848,12 @@ fn pool_slash_proportional() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 50 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 50,
+				slash_era: 100,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -765,7 +887,10 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 40, 10, 10, 10));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -780,7 +905,7 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 		assert_ok!(Pools::join(RuntimeOrigin::signed(20), bond, 1));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 60 }]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -792,7 +917,12 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 40,
+				withdraw_era: 127
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -818,7 +948,12 @@ fn pool_slash_non_proportional_only_bonded_pool() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 30 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 30,
+				slash_era: 100,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -844,7 +979,10 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 40, 10, 10, 10));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }]
+			vec![
+				StakingEvent::StakerOnboarded { stash: POOL1_BONDED },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 }
+			]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -859,7 +997,7 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		assert_ok!(Pools::join(RuntimeOrigin::signed(20), bond, 1));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Bonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::BondedExtra { stash: POOL1_BONDED, amount: bond, new_active: 60 }]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -871,7 +1009,12 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, bond));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Unbonded { stash: POOL1_BONDED, amount: bond }]
+			vec![StakingEvent::Unbonded {
+				stash: POOL1_BONDED,
+				amount: bond,
+				remaining_active: 40,
+				withdraw_era: 127
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
@@ -897,7 +1040,12 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![StakingEvent::Slashed { staker: POOL1_BONDED, amount: 50 }]
+			vec![StakingEvent::Slashed {
+				staker: POOL1_BONDED,
+				amount: 50,
+				slash_era: 100,
+				span_index: 0
+			}]
 		);
 		assert_eq!(
 			pool_events_since_last_call(),
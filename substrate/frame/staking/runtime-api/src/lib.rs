@@ -19,12 +19,13 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
+use codec::{Codec, HasCompact};
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 	pub trait StakingApi<Balance, AccountId>
 		where
-			Balance: Codec,
+			Balance: Codec + HasCompact,
 			AccountId: Codec,
 	{
 		/// Returns the nominations quota for a nominator with a given balance.
@@ -35,5 +36,124 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
+
+		/// Returns a summary of the given era, or `None` if the era is not within history depth.
+		fn era_summary(era: sp_staking::EraIndex) -> Option<sp_staking::EraSummary<Balance>>;
+
+		/// Returns the oldest era within history depth for which validator `account` has unclaimed
+		/// rewards, if any.
+		fn next_payout_era(account: AccountId) -> Option<sp_staking::EraIndex>;
+
+		/// Returns the number of validators elected for the current era, the desired validator
+		/// count, the minimum validator count and the maximum number of election winners, in this
+		/// order.
+		fn validator_count_status() -> (u32, u32, u32, u32);
+
+		/// Returns the page of the exposure of `validator` in `era` that contains `nominator`, or
+		/// `None` if `nominator` is not exposed to `validator` in that era.
+		fn exposure_page_of_nominator(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+			nominator: AccountId,
+		) -> Option<sp_staking::Page>;
+
+		/// Returns the total reward that can still be paid out for validator `account` and its
+		/// nominators in `era`, across all unclaimed pages.
+		fn total_rewardable(era: sp_staking::EraIndex, account: AccountId) -> Balance;
+
+		/// Returns all the accounts restricted from bonding.
+		///
+		/// Iterates over all restricted accounts, hence it is only meant to be called off-chain.
+		fn restricted_accounts() -> Vec<AccountId>;
+
+		/// Returns the approval stake of `validator`: its own stake plus the stake of all its current
+		/// nominators.
+		///
+		/// Iterates over all nominators, hence it is only meant to be called off-chain.
+		fn approval_stake(validator: AccountId) -> Balance;
+
+		/// Returns the number of validators, i.e. stashes that intend to validate.
+		fn validator_count() -> u32;
+
+		/// Returns the number of nominators, i.e. stashes that intend to nominate.
+		fn nominator_count() -> u32;
+
+		/// Returns the slashes of `stash` as `(era, amount)` pairs, ordered by era.
+		///
+		/// The amount of an era sums the slashes of `stash` as a validator and as a nominator.
+		/// Slashes are only known for the eras within the bonding duration, older ones are pruned.
+		fn slash_history(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)>;
+
+		/// Returns the validators rewarded in `era` with their reward points, sorted by points in
+		/// descending order.
+		///
+		/// Sorts all the validators rewarded in `era`, hence it is only meant to be called off-chain.
+		fn reward_points_leaderboard(era: sp_staking::EraIndex) -> Vec<(AccountId, u32)>;
+
+		/// Returns all the eras within history depth for which validator `account` has unclaimed
+		/// rewards, oldest first.
+		///
+		/// Checks every era within history depth, hence it is only meant to be called off-chain.
+		fn pending_payout_eras(account: AccountId) -> Vec<sp_staking::EraIndex>;
+
+		/// Returns the minimum active bond needed to take `role`.
+		fn minimum_bond(role: sp_staking::StakerRole) -> Balance;
+
+		/// Returns the integrity state of the ledger bonded by `stash`, or `None` if `stash` is not
+		/// bonded.
+		fn ledger_integrity(stash: AccountId) -> Option<sp_staking::LedgerIntegrityState>;
+
+		/// Returns the change of the exposure of `validator` from `era_a` to `era_b`, or `None` if
+		/// `validator` has no exposure in either era.
+		fn exposure_delta(
+			era_a: sp_staking::EraIndex,
+			era_b: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_staking::ExposureDelta<Balance>>;
+
+		/// Returns an estimate of the annual yield of staking behind `validator`, after commission,
+		/// based on its reward in the last completed era.
+		fn apy_estimate(validator: AccountId) -> Option<sp_runtime::Perbill>;
+
+		/// Returns the stashes of all the bonded stakers that are chilled, i.e. neither validating
+		/// nor nominating.
+		///
+		/// Iterates over all bonded stakers, hence it is only meant to be called off-chain.
+		fn chilled_stakers() -> Vec<AccountId>;
+
+		/// Returns the rewards that nominator `who` could still claim in each era within history
+		/// depth, oldest first.
+		///
+		/// Iterates over all exposure pages within history depth, hence it is only meant to be
+		/// called off-chain.
+		fn pending_rewards_for(who: AccountId) -> Vec<(sp_staking::EraIndex, Balance)>;
+
+		/// Returns all the nominators backing `validator` in `era` along with the value of their
+		/// backing, across all the pages of its exposure.
+		///
+		/// The result can be as large as the total number of nominators, hence it is only meant to
+		/// be called off-chain.
+		fn validator_backers(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Vec<(AccountId, Balance)>;
+
+		/// Returns the stashes of all the validator candidates, elected or not.
+		///
+		/// Iterates over all the validators, hence it is only meant to be called off-chain.
+		fn election_candidates() -> Vec<AccountId>;
+
+		/// Returns the reward points of each of `validators` in `era`, 0 for the ones without any.
+		fn era_points_for(
+			era: sp_staking::EraIndex,
+			validators: Vec<AccountId>,
+		) -> Vec<(AccountId, u32)>;
+
+		/// Returns the full exposure of `validator` in `era`, merging all of its exposure pages, or
+		/// `None` if it has no exposure in `era`.
+		fn full_exposure(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_staking::Exposure<AccountId, Balance>>;
 	}
 }
@@ -485,6 +485,16 @@ benchmarks! {
 		assert_eq!(Payee::<T>::get(&stash), Some(RewardDestination::Account(controller)));
 	}
 
+	set_min_nominator_stake {
+		let (stash, controller) = create_stash_controller::<T>(USER_SEED, 100, RewardDestination::Staked)?;
+		Staking::<T>::validate(RawOrigin::Signed(controller.clone()).into(), Default::default())?;
+		let min: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller), Some(min))
+	verify {
+		assert_eq!(ValidatorNominationFilter::<T>::get(&stash), Some(min));
+	}
+
 	set_controller {
 		let (stash, ctlr) = create_unique_stash_controller::<T>(9000, 100, RewardDestination::Staked, false)?;
 		// ensure `ctlr` is the currently stored controller.
@@ -505,6 +515,21 @@ benchmarks! {
 		assert_eq!(ValidatorCount::<T>::get(), validator_count);
 	}
 
+	set_validator_count_and_min {
+		let validator_count = MaxValidators::<T>::get();
+	}: _(RawOrigin::Root, validator_count, 1)
+	verify {
+		assert_eq!(ValidatorCount::<T>::get(), validator_count);
+		assert_eq!(MinimumValidatorCount::<T>::get(), 1);
+	}
+
+	set_commission_change_rate {
+		let limit = CommissionChangeRate { max_increase: Perbill::from_percent(5), min_delay: 1 };
+	}: _(RawOrigin::Root, Some(limit))
+	verify {
+		assert_eq!(CommissionChangeRateLimit::<T>::get(), Some(limit));
+	}
+
 	force_no_eras {}: _(RawOrigin::Root)
 	verify { assert_eq!(ForceEra::<T>::get(), Forcing::ForceNone); }
 
@@ -962,6 +987,90 @@ benchmarks! {
 		assert_eq!(Staking::<T>::inspect_bond_state(&stash), Ok(LedgerIntegrityState::Ok));
 	}
 
+	prune_slashing_spans {
+		let s in 1 .. MAX_SPANS;
+		let stash: T::AccountId = account("stash", 0, SEED);
+		add_slashing_spans::<T>(&stash, s);
+		// move the bonding window past all the spans.
+		ActiveEra::<T>::put(ActiveEraInfo {
+			index: s.saturating_add(T::BondingDuration::get()).saturating_add(1),
+			start: None,
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), stash.clone(), s)
+	verify {
+		assert_eq!(SlashingSpans::<T>::get(&stash).map(|s| s.iter().count()), Some(1));
+	}
+
+	restrict_account {
+		let who: T::AccountId = account("restricted", 0, SEED);
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(RestrictedAccounts::<T>::contains_key(&who));
+	}
+
+	unrestrict_account {
+		let who: T::AccountId = account("restricted", 0, SEED);
+		RestrictedAccounts::<T>::insert(&who, ());
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(!RestrictedAccounts::<T>::contains_key(&who));
+	}
+
+	set_sessions_per_era {
+	}: _(RawOrigin::Root, Some(10))
+	verify {
+		assert_eq!(PendingSessionsPerEraOverride::<T>::get(), Some(Some(10)));
+	}
+
+	cancel_payee_change {
+		let (stash, controller) = create_stash_controller::<T>(USER_SEED, 100, RewardDestination::Staked)?;
+		PendingPayee::<T>::insert(&stash, (1, RewardDestination::Account(controller.clone())));
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller))
+	verify {
+		assert!(!PendingPayee::<T>::contains_key(&stash));
+	}
+
+	bond_extra_from_escrow {
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		let origin_weight = MinNominatorBond::<T>::get().max(T::Currency::minimum_balance());
+
+		// setup the worst case list scenario.
+		let scenario = ListScenario::<T>::new(origin_weight, true)?;
+		let max_additional = scenario.dest_weight - origin_weight;
+
+		let stash = scenario.origin_stash1.clone();
+		let controller = scenario.origin_controller1;
+		let original_bonded: BalanceOf<T>
+			= Ledger::<T>::get(&controller).map(|l| l.active).ok_or("ledger not created after")?;
+
+		let escrow: T::AccountId = account("escrow", USER_SEED, SEED);
+		let _ = T::Currency::make_free_balance_be(
+			&escrow,
+			max_additional + T::Currency::minimum_balance(),
+		);
+		EscrowApprovals::<T>::insert(&escrow, &stash, ());
+
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller.clone()), max_additional, T::Lookup::unlookup(escrow))
+	verify {
+		let ledger = Ledger::<T>::get(&controller).ok_or("ledger not created after")?;
+		let new_bonded: BalanceOf<T> = ledger.active;
+		assert!(original_bonded < new_bonded);
+	}
+
+	set_escrow_approval {
+		let escrow = create_funded_user::<T>("escrow", USER_SEED, 100);
+		let stash: T::AccountId = account("stash", USER_SEED, SEED);
+		whitelist_account!(escrow);
+	}: _(RawOrigin::Signed(escrow.clone()), T::Lookup::unlookup(stash.clone()), true)
+	verify {
+		assert!(EscrowApprovals::<T>::contains_key(&escrow, &stash));
+	}
+
 	impl_benchmark_test_suite!(
 		Staking,
 		crate::mock::ExtBuilder::default().has_stakers(true),
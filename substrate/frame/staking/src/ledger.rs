@@ -25,6 +25,8 @@
 //!   instances of [`StakingLedger`] keyed by the staker's controller account and should be mutated
 //!   and read through the [`StakingLedger`] API;
 //! * [`Payee`]: mutates and reads the reward destination preferences for a bonded stash.
+//! * [`BondedCount`]: keeps track of the number of bonded stashes.
+//! * [`TotalUnlocking`]: keeps track of the balance being unlocked across all the ledgers.
 //! * Staking locks: mutates the locks for staking.
 //!
 //! NOTE: All the storage operations related to the staking ledger (both reads and writes) *MUST* be
@@ -35,17 +37,15 @@ use frame_support::{
 	defensive, ensure,
 	traits::{Defensive, LockableCurrency},
 };
+use sp_runtime::traits::{Saturating, Zero};
 use sp_staking::{StakingAccount, StakingInterface};
 use sp_std::prelude::*;
 
 use crate::{
-	BalanceOf, Bonded, Config, Error, Ledger, Pallet, Payee, RewardDestination, StakingLedger,
-	VirtualStakers, STAKING_ID,
+	BalanceOf, Bonded, BondedCount, Config, Error, Ledger, Pallet, Payee, PendingPayee,
+	RewardDestination, StakingLedger, TotalUnlocking, VirtualStakers, STAKING_ID,
 };
 
-#[cfg(any(feature = "runtime-benchmarks", test))]
-use sp_runtime::traits::Zero;
-
 impl<T: Config> StakingLedger<T> {
 	#[cfg(any(feature = "runtime-benchmarks", test))]
 	pub fn default_from(stash: T::AccountId) -> Self {
@@ -176,6 +176,13 @@ impl<T: Config> StakingLedger<T> {
 		})
 	}
 
+	/// Returns the balance of all the unlocking chunks of the ledger.
+	pub(crate) fn unlocking_balance(&self) -> BalanceOf<T> {
+		self.unlocking
+			.iter()
+			.fold(Zero::zero(), |acc, chunk| acc.saturating_add(chunk.value))
+	}
+
 	/// Inserts/updates a staking ledger account.
 	///
 	/// Bonds the ledger if it is not bonded yet, signalling that this is a new ledger. The staking
@@ -199,13 +206,17 @@ impl<T: Config> StakingLedger<T> {
 			);
 		}
 
-		Ledger::<T>::insert(
-			&self.controller().ok_or_else(|| {
-				defensive!("update called on a ledger that is not bonded.");
-				Error::<T>::NotController
-			})?,
-			&self,
-		);
+		let controller = self.controller().ok_or_else(|| {
+			defensive!("update called on a ledger that is not bonded.");
+			Error::<T>::NotController
+		})?;
+
+		let old_unlocking =
+			Ledger::<T>::get(&controller).map(|l| l.unlocking_balance()).unwrap_or_default();
+		TotalUnlocking::<T>::mutate(|total| {
+			*total = total.saturating_sub(old_unlocking).saturating_add(self.unlocking_balance())
+		});
+		Ledger::<T>::insert(&controller, &self);
 
 		Ok(())
 	}
@@ -220,7 +231,10 @@ impl<T: Config> StakingLedger<T> {
 
 		<Payee<T>>::insert(&self.stash, payee);
 		<Bonded<T>>::insert(&self.stash, &self.stash);
-		self.update()
+		self.update()?;
+
+		BondedCount::<T>::mutate(|count| *count = count.saturating_add(1));
+		Ok(())
 	}
 
 	/// Sets the ledger Payee.
@@ -266,6 +280,11 @@ impl<T: Config> StakingLedger<T> {
 			Ledger::<T>::remove(controller);
 			<Bonded<T>>::remove(&stash);
 			<Payee<T>>::remove(&stash);
+			<PendingPayee<T>>::remove(&stash);
+			BondedCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			TotalUnlocking::<T>::mutate(|total| {
+				*total = total.saturating_sub(ledger.unlocking_balance())
+			});
 
 			// kill virtual staker if it exists.
 			if <VirtualStakers<T>>::take(&stash).is_none() {
@@ -308,7 +308,8 @@ use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use frame_support::{
 	defensive, defensive_assert,
 	traits::{
-		ConstU32, Currency, Defensive, DefensiveMax, DefensiveSaturating, Get, LockIdentifier,
+		CallerTrait, ConstU32, Currency, Defensive, DefensiveMax, DefensiveSaturating,
+		EnsureOrigin, Get, LockIdentifier, OriginTrait,
 	},
 	weights::Weight,
 	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
@@ -317,14 +318,15 @@ use scale_info::TypeInfo;
 use sp_runtime::{
 	curve::PiecewiseLinear,
 	traits::{AtLeast32BitUnsigned, Convert, StaticLookup, Zero},
-	Perbill, Perquintill, Rounding, RuntimeDebug, Saturating,
+	DispatchError, DispatchResult, Perbill, Percent, Perquintill, Rounding, RuntimeDebug,
+	Saturating,
 };
 use sp_staking::{
 	offence::{Offence, OffenceError, ReportOffence},
 	EraIndex, ExposurePage, OnStakingUpdate, Page, PagedExposureMetadata, SessionIndex,
 	StakingAccount,
 };
-pub use sp_staking::{Exposure, IndividualExposure, StakerStatus};
+pub use sp_staking::{Exposure, IndividualExposure, LedgerIntegrityState, StakerStatus};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 pub use weights::WeightInfo;
 
@@ -355,6 +357,9 @@ pub type MaxNominationsOf<T> =
 /// Counter for the number of "reward" points earned by a given validator.
 pub type RewardPoint = u32;
 
+/// Identifier of a nomination pool, as understood by [`Config::PoolInterface`].
+pub type PoolId = u32;
+
 /// The balance type of this pallet.
 pub type BalanceOf<T> = <T as Config>::CurrencyBalance;
 
@@ -411,6 +416,34 @@ pub enum RewardDestination<AccountId> {
 	Account(AccountId),
 	/// Receive no reward.
 	None,
+	/// Pay into the stash account and credit it to the stash's position in the given nomination
+	/// pool, see [`Config::PoolInterface`].
+	Pool(PoolId),
+	/// Add `portion` of the reward to the amount at stake, as with [`RewardDestination::Staked`],
+	/// and pay the rest into `account`.
+	Split { account: AccountId, portion: Perbill },
+}
+
+/// The stakes affected by the slash of an offence, see [`Pallet::on_offence_with_scope`].
+#[derive(
+	PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub enum SlashScope {
+	/// The validator and its nominators are slashed.
+	#[default]
+	Full,
+	/// Only the own stake of the validator is slashed, its nominators are left untouched.
+	ValidatorOnly,
+}
+
+/// The balance of the stash that [`Call::bond_extra_from`] bonds.
+///
+/// Reserved balance is deliberately not a source: it belongs to the pallets that reserved it,
+/// e.g. as identity or proxy deposits, which would silently lose track of it once bonded.
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum FundsSource {
+	/// The free balance of the stash, as with [`Call::bond_extra`].
+	Free,
 }
 
 /// Preference of what happens regarding validation.
@@ -491,20 +524,6 @@ pub struct StakingLedger<T: Config> {
 	controller: Option<T::AccountId>,
 }
 
-/// State of a ledger with regards with its data and metadata integrity.
-#[derive(PartialEq, Debug)]
-enum LedgerIntegrityState {
-	/// Ledger, bond and corresponding staking lock is OK.
-	Ok,
-	/// Ledger and/or bond is corrupted. This means that the bond has a ledger with a different
-	/// stash than the bonded stash.
-	Corrupted,
-	/// Ledger was corrupted and it has been killed.
-	CorruptedKilled,
-	/// Ledger and bond are OK, however the ledger's stash lock is out of sync.
-	LockCorrupted,
-}
-
 impl<T: Config> StakingLedger<T> {
 	/// Remove entries from `unlocking` that are sufficiently old and reduce the
 	/// total by the sum of their balances.
@@ -564,6 +583,23 @@ impl<T: Config> StakingLedger<T> {
 		(self, unlocking_balance)
 	}
 
+	/// Re-bond up to `value` of the chunk of funds scheduled for unlocking in `era`.
+	///
+	/// Returns the updated ledger and the amount rebonded, or `None` if no chunk is scheduled for
+	/// unlocking in `era`.
+	fn rebond_chunk(mut self, era: EraIndex, value: BalanceOf<T>) -> Option<(Self, BalanceOf<T>)> {
+		let index = self.unlocking.iter().position(|chunk| chunk.era == era)?;
+		let chunk = &mut self.unlocking[index];
+		let rebonded = value.min(chunk.value);
+		chunk.value -= rebonded;
+		if chunk.value.is_zero() {
+			self.unlocking.remove(index);
+		}
+		self.active += rebonded;
+
+		Some((self, rebonded))
+	}
+
 	/// Slash the staker for a given amount of balance.
 	///
 	/// This implements a proportional slashing system, whereby we set our preference to slash as
@@ -839,6 +875,33 @@ impl<Balance, const MAX: u32> NominationsQuota<Balance> for FixedNominationsQuot
 	}
 }
 
+/// An origin check that succeeds only for signed origins, returning the signer as the controller.
+///
+/// This is the default [`Config::PayeeControlOrigin`], i.e. only the controller itself can update
+/// its reward destination.
+pub struct EnsureSignedController;
+impl<O: OriginTrait> EnsureOrigin<O> for EnsureSignedController
+where
+	O::AccountId: Clone + Decode,
+{
+	type Success = O::AccountId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.try_with_caller(|caller| match caller.as_system_ref() {
+			Some(frame_system::RawOrigin::Signed(who)) => Ok(who.clone()),
+			_ => Err(caller),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		let zero_account_id =
+			O::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
+				.map_err(|_| ())?;
+		Ok(O::signed(zero_account_id))
+	}
+}
+
 /// Means for interacting with a specialized version of the `session` trait.
 ///
 /// This is needed because `Staking` sets the `ValidatorIdOf` of the `pallet_session::Config`
@@ -848,6 +911,8 @@ pub trait SessionInterface<AccountId> {
 	fn disable_validator(validator_index: u32) -> bool;
 	/// Get the validators from session.
 	fn validators() -> Vec<AccountId>;
+	/// Get the indices of the currently disabled validators in the session validator set.
+	fn disabled_validators() -> Vec<u32>;
 	/// Prune historical session tries up to but not including the given index.
 	fn prune_historical_up_to(up_to: SessionIndex);
 }
@@ -874,6 +939,10 @@ where
 		<pallet_session::Pallet<T>>::validators()
 	}
 
+	fn disabled_validators() -> Vec<u32> {
+		<pallet_session::Pallet<T>>::disabled_validators()
+	}
+
 	fn prune_historical_up_to(up_to: SessionIndex) {
 		<pallet_session::historical::Pallet<T>>::prune_up_to(up_to);
 	}
@@ -886,6 +955,9 @@ impl<AccountId> SessionInterface<AccountId> for () {
 	fn validators() -> Vec<AccountId> {
 		Vec::new()
 	}
+	fn disabled_validators() -> Vec<u32> {
+		Vec::new()
+	}
 	fn prune_historical_up_to(_: SessionIndex) {
 		()
 	}
@@ -972,6 +1044,56 @@ impl Default for Forcing {
 	}
 }
 
+/// The role a stash had when it was chilled.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ChillRole {
+	/// The stash was a validator.
+	WasValidator,
+	/// The stash was a nominator.
+	WasNominator,
+	/// The stash was neither validating nor nominating, chilling had no effect.
+	WasIdle,
+}
+
+/// The staking configurations that would result from a [`Call::set_staking_configs`], as
+/// returned by [`Pallet::preview_staking_configs`].
+#[derive(PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct StakingConfigPreview<Balance> {
+	/// The resulting [`MinNominatorBond`].
+	pub min_nominator_bond: Balance,
+	/// The resulting [`MinValidatorBond`].
+	pub min_validator_bond: Balance,
+	/// The resulting [`MaxNominatorsCount`].
+	pub max_nominator_count: Option<u32>,
+	/// The resulting [`MaxValidatorsCount`].
+	pub max_validator_count: Option<u32>,
+	/// The resulting `ChillThreshold`.
+	pub chill_threshold: Option<Percent>,
+	/// The resulting [`MinCommission`].
+	pub min_commission: Perbill,
+	/// The resulting [`MaxStakedRewards`].
+	pub max_staked_rewards: Option<Percent>,
+}
+
+/// A limit on how fast validators can raise their commission, see
+/// [`Call::set_commission_change_rate`].
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CommissionChangeRate {
+	/// The maximum increase of the commission in a single update.
+	pub max_increase: Perbill,
+	/// The minimum number of eras between two increases of the commission.
+	pub min_delay: EraIndex,
+}
+
+/// The last known commission of a validator, see [`LastCommissionUpdate`].
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CommissionUpdate {
+	/// The commission the validator last validated with.
+	pub commission: Perbill,
+	/// The active era in which the validator last raised its commission, if ever.
+	pub raised_in: Option<EraIndex>,
+}
+
 /// A `Convert` implementation that finds the stash of the given controller account,
 /// if any.
 pub struct StashOf<T>(sp_std::marker::PhantomData<T>);
@@ -1266,6 +1388,67 @@ impl BenchmarkingConfig for TestBenchmarkingConfig {
 	type MaxNominators = frame_support::traits::ConstU32<100>;
 }
 
+/// Something that can exempt offences from being slashed.
+///
+/// This can be used, for instance, to waive slashes for validators that a heartbeat pallet
+/// attests to have been online.
+pub trait OffenceExemptionProvider<AccountId> {
+	/// Whether the offence committed by `validator` in `slash_era` should not be slashed.
+	fn is_exempt(validator: &AccountId, slash_era: EraIndex) -> bool;
+}
+
+impl<AccountId> OffenceExemptionProvider<AccountId> for () {
+	fn is_exempt(_validator: &AccountId, _slash_era: EraIndex) -> bool {
+		false
+	}
+}
+
+/// Something that can route staking rewards into a nomination pool, backing
+/// [`RewardDestination::Pool`].
+pub trait PoolRewardInterface<AccountId, Balance> {
+	/// Whether the rewards of `member` can be routed into pool `pool_id`.
+	///
+	/// [`RewardDestination::Pool`] can only be selected if this returns `true`.
+	fn can_route(pool_id: PoolId, member: &AccountId) -> bool;
+
+	/// Credit `amount`, which has just been paid into the free balance of `member`, to its
+	/// position in pool `pool_id`.
+	fn credit_reward(pool_id: PoolId, member: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+impl<AccountId, Balance> PoolRewardInterface<AccountId, Balance> for () {
+	fn can_route(_pool_id: PoolId, _member: &AccountId) -> bool {
+		false
+	}
+
+	fn credit_reward(_pool_id: PoolId, _member: &AccountId, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Other("no pool interface"))
+	}
+}
+
+/// Something that reacts to a new era becoming active.
+pub trait OnEraStart {
+	/// Called when `era` becomes the active era.
+	///
+	/// At this point the exposures of `era` are known and the slashes due in `era` are applied.
+	fn on_era_start(era: EraIndex);
+}
+
+impl OnEraStart for () {
+	fn on_era_start(_era: EraIndex) {}
+}
+
+/// Something that reacts to the active era coming to an end.
+pub trait OnEraEnd {
+	/// Called when the active `era` ends, after its validator payout has been computed and
+	/// before the next era becomes active.
+	fn on_era_end(era: EraIndex);
+}
+
+impl OnEraEnd for () {
+	fn on_era_end(_era: EraIndex) {}
+}
+
 /// Controls validator disabling
 pub trait DisablingStrategy<T: Config> {
 	/// Make a disabling decision. Returns the index of the validator to disable or `None` if no new
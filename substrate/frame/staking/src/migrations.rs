@@ -60,6 +60,77 @@ impl Default for ObsoleteReleases {
 #[storage_alias]
 type StorageVersion<T: Config> = StorageValue<Pallet<T>, ObsoleteReleases, ValueQuery>;
 
+/// Initializing `TotalUnlocking` with the unlocking balance of all existing ledgers.
+pub mod v17 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateV16ToV17<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV16ToV17<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let (count, total) = Ledger::<T>::iter_values()
+				.fold((0u32, BalanceOf::<T>::zero()), |(c, t), l| {
+					(c.saturating_add(1), t.saturating_add(l.unlocking_balance()))
+				});
+			TotalUnlocking::<T>::put(total);
+
+			log!(info, "v17 applied successfully, {:?} unlocking.", total);
+			T::DbWeight::get().reads_writes(count.saturating_add(1).into(), 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			frame_support::ensure!(
+				TotalUnlocking::<T>::get() ==
+					Ledger::<T>::iter_values().fold(BalanceOf::<T>::zero(), |t, l| t
+						.saturating_add(l.unlocking_balance())),
+				"TotalUnlocking does not match the ledgers after the migration"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateV16ToV17<T> = VersionedMigration<
+		16,
+		17,
+		VersionUncheckedMigrateV16ToV17<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Initializing `BondedCount` with the number of existing ledgers.
+pub mod v16 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateV15ToV16<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV15ToV16<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let count = Ledger::<T>::iter_keys().count() as u32;
+			BondedCount::<T>::put(count);
+
+			log!(info, "v16 applied successfully, {} bonded stashes.", count);
+			T::DbWeight::get().reads_writes(count.saturating_add(1).into(), 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			frame_support::ensure!(
+				BondedCount::<T>::get() as usize == Ledger::<T>::iter_keys().count(),
+				"BondedCount does not match the number of ledgers after the migration"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateV15ToV16<T> = VersionedMigration<
+		15,
+		16,
+		VersionUncheckedMigrateV15ToV16<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
 /// Migrating `OffendingValidators` from `Vec<(u32, bool)>` to `Vec<u32>`
 pub mod v15 {
 	use super::*;
@@ -362,7 +433,7 @@ pub mod v10 {
 						// in the old slashing scheme, the slash era was the key at which we read
 						// from `UnappliedSlashes`.
 						log!(warn, "prematurely applying a slash ({:?}) for era {:?}", slash, era);
-						slashing::apply_slash::<T>(slash, era);
+						slashing::apply_slash::<T>(slash, era, era);
 					}
 				}
 
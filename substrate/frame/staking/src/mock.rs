@@ -23,10 +23,10 @@ use frame_election_provider_support::{
 	onchain, SequentialPhragmen, VoteWeight,
 };
 use frame_support::{
-	assert_ok, derive_impl, ord_parameter_types, parameter_types,
+	assert_ok, derive_impl, ensure, ord_parameter_types, parameter_types,
 	traits::{
-		ConstU64, Currency, EitherOfDiverse, FindAuthor, Get, Hooks, Imbalance, LockableCurrency,
-		OnUnbalanced, OneSessionHandler, WithdrawReasons,
+		ConstU64, Currency, EitherOfDiverse, EnsureOrigin, FindAuthor, Get, Hooks, Imbalance,
+		LockableCurrency, OnUnbalanced, OneSessionHandler, WithdrawReasons,
 	},
 	weights::constants::RocksDbWeight,
 };
@@ -113,9 +113,13 @@ parameter_types! {
 	pub static SessionsPerEra: SessionIndex = 3;
 	pub static ExistentialDeposit: Balance = 1;
 	pub static SlashDeferDuration: EraIndex = 0;
+	pub static SlashDeferThreshold: Option<Perbill> = None;
 	pub static Period: BlockNumber = 5;
 	pub static Offset: BlockNumber = 0;
 	pub static MaxControllersInDeprecationBatch: u32 = 5900;
+	pub static MaxChillOtherBatch: u32 = 16;
+	pub static MaxForceUnstakeBatch: u32 = 16;
+	pub static MaxPayoutBatch: u32 = 8;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -178,6 +182,25 @@ pallet_staking_reward_curve::build! {
 parameter_types! {
 	pub const BondingDuration: EraIndex = 3;
 	pub const RewardCurve: &'static PiecewiseLinear<'static> = &I_NPOS;
+	pub static EraPayoutOverride: Option<(Balance, Balance)> = None;
+}
+
+/// Pays out according to [`RewardCurve`], unless [`EraPayoutOverride`] is set.
+pub struct EraPayoutMock;
+impl EraPayout<Balance> for EraPayoutMock {
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		EraPayoutOverride::get().unwrap_or_else(|| {
+			ConvertCurve::<RewardCurve>::era_payout(
+				total_staked,
+				total_issuance,
+				era_duration_millis,
+			)
+		})
+	}
 }
 
 parameter_types! {
@@ -203,6 +226,18 @@ parameter_types! {
 	pub static HistoryDepth: u32 = 80;
 	pub static MaxExposurePageSize: u32 = 64;
 	pub static MaxUnlockingChunks: u32 = 32;
+	pub static AutoChillOnUnbond: bool = false;
+	pub static ChillOtherRequiresCountLimit: bool = true;
+	pub static MaxLedgerTotal: Option<Balance> = None;
+	pub static BondEdBuffer: Balance = 0;
+	pub static NominationExpiryScanLimit: u32 = 64;
+	pub static MinEventedUnbond: Balance = 0;
+	pub static IdleReapEras: Option<EraIndex> = None;
+	pub static ExpectedBlockTime: u64 = BLOCK_TIME;
+	pub static PayeeChangeDelay: Option<EraIndex> = None;
+	pub static PendingPayeeScanLimit: u32 = 64;
+	pub static AutoPayout: bool = false;
+	pub static MaxAutoPayoutsPerBlock: u32 = 16;
 	pub static RewardOnUnbalanceWasCalled: bool = false;
 	pub static MaxWinners: u32 = 100;
 	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
@@ -241,6 +276,32 @@ parameter_types! {
 		(BalanceOf<Test>, BTreeMap<EraIndex, BalanceOf<Test>>) =
 		(Zero::zero(), BTreeMap::new());
 	pub static SlashObserver: BTreeMap<AccountId, BalanceOf<Test>> = BTreeMap::new();
+	pub static ExemptValidators: Vec<AccountId> = vec![];
+	pub static PoolMembers: Vec<(PoolId, AccountId)> = vec![];
+	pub static PoolRewardCredits: Vec<(PoolId, AccountId, Balance)> = vec![];
+	pub static EraHookCalls: Vec<EraHookCall> = vec![];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EraHookCall {
+	/// `era` started, `exposed` tells whether its exposures were already set up.
+	Start { era: EraIndex, exposed: bool },
+	/// `era` ended, `rewarded` tells whether its validator payout was already computed.
+	End { era: EraIndex, rewarded: bool },
+}
+
+pub struct EraHooksMock;
+impl OnEraStart for EraHooksMock {
+	fn on_era_start(era: EraIndex) {
+		let exposed = ErasStakersOverview::<Test>::iter_prefix(era).next().is_some();
+		EraHookCalls::mutate(|calls| calls.push(EraHookCall::Start { era, exposed }));
+	}
+}
+impl OnEraEnd for EraHooksMock {
+	fn on_era_end(era: EraIndex) {
+		let rewarded = ErasValidatorReward::<Test>::contains_key(era);
+		EraHookCalls::mutate(|calls| calls.push(EraHookCall::End { era, rewarded }));
+	}
 }
 
 pub struct EventListenerMock;
@@ -258,6 +319,26 @@ impl OnStakingUpdate<AccountId, Balance> for EventListenerMock {
 	}
 }
 
+pub struct OffenceExemptionMock;
+impl OffenceExemptionProvider<AccountId> for OffenceExemptionMock {
+	fn is_exempt(validator: &AccountId, _slash_era: EraIndex) -> bool {
+		ExemptValidators::get().contains(validator)
+	}
+}
+
+pub struct PoolInterfaceMock;
+impl PoolRewardInterface<AccountId, Balance> for PoolInterfaceMock {
+	fn can_route(pool_id: PoolId, member: &AccountId) -> bool {
+		PoolMembers::get().contains(&(pool_id, *member))
+	}
+
+	fn credit_reward(pool_id: PoolId, member: &AccountId, amount: Balance) -> DispatchResult {
+		ensure!(Self::can_route(pool_id, member), DispatchError::Other("not a member"));
+		PoolRewardCredits::mutate(|credits| credits.push((pool_id, *member, amount)));
+		Ok(())
+	}
+}
+
 // Disabling threshold for `UpToLimitDisablingStrategy`
 pub(crate) const DISABLING_LIMIT_FACTOR: usize = 3;
 
@@ -269,9 +350,14 @@ impl crate::pallet::pallet::Config for Test {
 	type Reward = MockReward;
 	type SessionsPerEra = SessionsPerEra;
 	type SlashDeferDuration = SlashDeferDuration;
+	type SlashDeferThreshold = SlashDeferThreshold;
 	type AdminOrigin = EnsureOneOrRoot;
+	type PayeeControlOrigin = EnsurePayeeProxyOrController;
+	type PayeeChangeDelay = PayeeChangeDelay;
+	type PendingPayeeScanLimit = PendingPayeeScanLimit;
+	type EscrowBondOrigin = EnsureSignedController;
 	type SessionInterface = Self;
-	type EraPayout = ConvertCurve<RewardCurve>;
+	type EraPayout = EraPayoutMock;
 	type NextNewSession = Session;
 	type MaxExposurePageSize = MaxExposurePageSize;
 	type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
@@ -281,10 +367,27 @@ impl crate::pallet::pallet::Config for Test {
 	type TargetList = UseValidatorsMap<Self>;
 	type NominationsQuota = WeightedNominationsQuota<16>;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
+	type AutoChillOnUnbond = AutoChillOnUnbond;
+	type ChillOtherRequiresCountLimit = ChillOtherRequiresCountLimit;
+	type MaxChillOtherBatch = MaxChillOtherBatch;
+	type MaxForceUnstakeBatch = MaxForceUnstakeBatch;
+	type MaxPayoutBatch = MaxPayoutBatch;
+	type MaxLedgerTotal = MaxLedgerTotal;
+	type BondEdBuffer = BondEdBuffer;
+	type NominationExpiryScanLimit = NominationExpiryScanLimit;
+	type MinEventedUnbond = MinEventedUnbond;
+	type IdleReapEras = IdleReapEras;
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type AutoPayout = AutoPayout;
+	type MaxAutoPayoutsPerBlock = MaxAutoPayoutsPerBlock;
 	type HistoryDepth = HistoryDepth;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
 	type EventListeners = EventListenerMock;
+	type OnEraStart = EraHooksMock;
+	type OnEraEnd = EraHooksMock;
 	type DisablingStrategy = pallet_staking::UpToLimitDisablingStrategy<DISABLING_LIMIT_FACTOR>;
+	type OffenceExemption = OffenceExemptionMock;
+	type PoolInterface = PoolInterfaceMock;
 }
 
 pub struct WeightedNominationsQuota<const MAX: u32>;
@@ -594,6 +697,16 @@ pub(crate) fn bond_nominator(who: AccountId, val: Balance, target: Vec<AccountId
 	assert_ok!(Staking::nominate(RuntimeOrigin::signed(who), target));
 }
 
+/// Bonds validator 11 with the 15 nominators `1000..1015`, which span two exposure pages once
+/// elected.
+pub(crate) fn bond_validator_with_two_exposure_pages() {
+	MaxExposurePageSize::set(10);
+	bond_validator(11, 1000);
+	for i in 0..15 {
+		bond_nominator(1000 + i, 1000 + i as Balance, vec![11]);
+	}
+}
+
 pub(crate) fn bond_virtual_nominator(
 	who: AccountId,
 	payee: AccountId,
@@ -914,6 +1027,30 @@ ord_parameter_types! {
 
 type EnsureOneOrRoot = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<One, AccountId>>;
 
+parameter_types! {
+	/// A `(proxy, controller)` pair that authorizes `proxy` to set the payee of `controller`.
+	pub static PayeeProxy: Option<(AccountId, AccountId)> = None;
+}
+
+/// Allows the controller itself or, if set, the [`PayeeProxy`] on behalf of its controller.
+pub struct EnsurePayeeProxyOrController;
+impl EnsureOrigin<RuntimeOrigin> for EnsurePayeeProxyOrController {
+	type Success = AccountId;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		let who = <EnsureSignedController as EnsureOrigin<RuntimeOrigin>>::try_origin(o)?;
+		Ok(match PayeeProxy::get() {
+			Some((proxy, controller)) if proxy == who => controller,
+			_ => who,
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		<EnsureSignedController as EnsureOrigin<RuntimeOrigin>>::try_successful_origin()
+	}
+}
+
 pub(crate) fn staking_events_since_last_call() -> Vec<crate::Event<Test>> {
 	let all: Vec<_> = System::events()
 		.into_iter()
@@ -20,7 +20,7 @@
 use frame_election_provider_support::{
 	bounds::{CountBound, SizeBound},
 	data_provider, BoundedSupportsOf, DataProviderBounds, ElectionDataProvider, ElectionProvider,
-	ScoreProvider, SortedListProvider, VoteWeight, VoterOf,
+	ElectionProviderBase, ScoreProvider, SortedListProvider, VoteWeight, VoterOf,
 };
 use frame_support::{
 	defensive,
@@ -44,7 +44,7 @@ use sp_runtime::{
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
 	offence::{OffenceDetails, OnOffenceHandler},
-	EraIndex, OnStakingUpdate, Page, SessionIndex, Stake,
+	EraIndex, EraSummary, ExposureDelta, OnStakingUpdate, Page, SessionIndex, Stake, StakerRole,
 	StakingAccount::{self, Controller, Stash},
 	StakingInterface,
 };
@@ -52,9 +52,10 @@ use sp_std::prelude::*;
 
 use crate::{
 	election_size_tracker::StaticTracker, log, slashing, weights::WeightInfo, ActiveEraInfo,
-	BalanceOf, EraInfo, EraPayout, Exposure, ExposureOf, Forcing, IndividualExposure,
-	LedgerIntegrityState, MaxNominationsOf, MaxWinnersOf, Nominations, NominationsQuota,
-	PositiveImbalanceOf, RewardDestination, SessionInterface, StakingLedger, ValidatorPrefs,
+	BalanceOf, ChillRole, CommissionUpdate, EraInfo, EraPayout, Exposure, ExposureOf, ExposurePage,
+	Forcing, IndividualExposure, LedgerIntegrityState, MaxNominationsOf, MaxWinnersOf, Nominations,
+	NominationsQuota, PagedExposure, PoolRewardInterface, PositiveImbalanceOf, RewardDestination,
+	RewardPoint, SessionInterface, SlashScope, StakingConfigPreview, StakingLedger, ValidatorPrefs,
 };
 
 use super::pallet::*;
@@ -87,6 +88,39 @@ impl<T: Config> Pallet<T> {
 		StakingLedger::<T>::paired_account(Stash(stash.clone()))
 	}
 
+	/// Whether `payee` would pay the rewards of `stash` back into `stash` itself.
+	///
+	/// Virtual stakers must not be paid out to their own stash since their locks are not managed
+	/// by this pallet.
+	pub(crate) fn is_self_payee(
+		stash: &T::AccountId,
+		payee: &RewardDestination<T::AccountId>,
+	) -> bool {
+		match payee {
+			RewardDestination::Staked | RewardDestination::Stash | RewardDestination::Pool(_) =>
+				true,
+			RewardDestination::Account(account) => account == stash,
+			// the staked portion is paid into the stash as well.
+			RewardDestination::Split { account, portion } => account == stash || !portion.is_zero(),
+			_ => false,
+		}
+	}
+
+	/// Ensure that the rewards of `stash` can actually be paid out to `payee`.
+	///
+	/// Only [`RewardDestination::Pool`] can be unavailable, if [`Config::PoolInterface`] cannot
+	/// route the rewards of `stash` into the pool. Any [`RewardDestination::Split`] is valid, a
+	/// `portion` of one is the same as [`RewardDestination::Staked`].
+	pub(crate) fn ensure_payee_available(
+		stash: &T::AccountId,
+		payee: &RewardDestination<T::AccountId>,
+	) -> Result<(), Error<T>> {
+		if let RewardDestination::Pool(pool_id) = payee {
+			ensure!(T::PoolInterface::can_route(*pool_id, stash), Error::<T>::PoolPayeeUnavailable);
+		}
+		Ok(())
+	}
+
 	/// Inspects and returns the corruption state of a ledger and direct bond, if any.
 	///
 	/// Note: all operations in this method access directly the `Bonded` and `Ledger` storage maps
@@ -172,9 +206,15 @@ impl<T: Config> Pallet<T> {
 
 		ledger.total = ledger.total.checked_add(&extra).ok_or(ArithmeticError::Overflow)?;
 		ledger.active = ledger.active.checked_add(&extra).ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			T::MaxLedgerTotal::get().map_or(true, |cap| ledger.total <= cap),
+			Error::<T>::LedgerCapExceeded
+		);
 		// last check: the new active amount of ledger must be more than ED.
 		ensure!(ledger.active >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
 
+		let new_active = ledger.active;
+
 		// NOTE: ledger must be updated prior to calling `Self::weight_of`.
 		ledger.update()?;
 		// update this staker in the sorted list, if they exist in it.
@@ -182,7 +222,11 @@ impl<T: Config> Pallet<T> {
 			let _ = T::VoterList::on_update(&stash, Self::weight_of(stash)).defensive();
 		}
 
-		Self::deposit_event(Event::<T>::Bonded { stash: stash.clone(), amount: extra });
+		Self::deposit_event(Event::<T>::BondedExtra {
+			stash: stash.clone(),
+			amount: extra,
+			new_active,
+		});
 
 		Ok(())
 	}
@@ -232,6 +276,7 @@ impl<T: Config> Pallet<T> {
 	pub(super) fn do_payout_stakers(
 		validator_stash: T::AccountId,
 		era: EraIndex,
+		caller: Option<T::AccountId>,
 	) -> DispatchResultWithPostInfo {
 		let controller = Self::bonded(&validator_stash).ok_or_else(|| {
 			Error::<T>::NotStash.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
@@ -244,13 +289,18 @@ impl<T: Config> Pallet<T> {
 					.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
 			})?;
 
-		Self::do_payout_stakers_by_page(validator_stash, era, page)
+		Self::do_payout_stakers_by_page(validator_stash, era, page, caller)
 	}
 
+	/// Pay out `page` of the rewards of `validator_stash` in `era`.
+	///
+	/// If the payout is requested by `caller`, [`Event::RewardClaimedBy`] is emitted after the
+	/// [`Event::Rewarded`] events.
 	pub(super) fn do_payout_stakers_by_page(
 		validator_stash: T::AccountId,
 		era: EraIndex,
 		page: Page,
+		caller: Option<T::AccountId>,
 	) -> DispatchResultWithPostInfo {
 		// Validate input data
 		let current_era = CurrentEra::<T>::get().ok_or_else(|| {
@@ -325,23 +375,20 @@ impl<T: Config> Pallet<T> {
 			return Ok(Some(T::WeightInfo::payout_stakers_alive_staked(0)).into())
 		}
 
-		// This is the fraction of the total reward that the validator and the
-		// nominators will get.
-		let validator_total_reward_part =
-			Perbill::from_rational(validator_reward_points, total_reward_points);
-
-		// This is how much validator + nominators are entitled to.
-		let validator_total_payout = validator_total_reward_part * era_payout;
-
 		let validator_commission = EraInfo::<T>::get_validator_commission(era, &ledger.stash);
-		// total commission validator takes across all nominator pages
-		let validator_total_commission_payout = validator_commission * validator_total_payout;
+		// the total commission validator takes across all nominator pages, and the rest that is
+		// shared among the exposure.
+		let (validator_leftover_payout, validator_total_commission_payout) =
+			Self::split_validator_payout(
+				era_payout,
+				validator_reward_points,
+				total_reward_points,
+				validator_commission,
+			);
 
-		let validator_leftover_payout =
-			validator_total_payout.defensive_saturating_sub(validator_total_commission_payout);
 		// Now let's calculate how this is split to the validator.
-		let validator_exposure_part = Perbill::from_rational(exposure.own(), exposure.total());
-		let validator_staking_payout = validator_exposure_part * validator_leftover_payout;
+		let validator_staking_payout =
+			Self::exposure_payout(exposure.own(), exposure.total(), validator_leftover_payout);
 		let page_stake_part = Perbill::from_rational(exposure.page_total(), exposure.total());
 		// validator commission is paid out in fraction across pages proportional to the page stake.
 		let validator_commission_payout = page_stake_part * validator_total_commission_payout;
@@ -356,7 +403,11 @@ impl<T: Config> Pallet<T> {
 		if let Some((imbalance, dest)) =
 			Self::make_payout(&stash, validator_staking_payout + validator_commission_payout)
 		{
-			Self::deposit_event(Event::<T>::Rewarded { stash, dest, amount: imbalance.peek() });
+			Self::deposit_event(Event::<T>::Rewarded {
+				stash: stash.clone(),
+				dest,
+				amount: imbalance.peek(),
+			});
 			total_imbalance.subsume(imbalance);
 		}
 
@@ -368,10 +419,8 @@ impl<T: Config> Pallet<T> {
 		// Lets now calculate how this is split to the nominators.
 		// Reward only the clipped exposures. Note this is not necessarily sorted.
 		for nominator in exposure.others().iter() {
-			let nominator_exposure_part = Perbill::from_rational(nominator.value, exposure.total());
-
-			let nominator_reward: BalanceOf<T> =
-				nominator_exposure_part * validator_leftover_payout;
+			let nominator_reward =
+				Self::exposure_payout(nominator.value, exposure.total(), validator_leftover_payout);
 			// We can now make nominator payout:
 			if let Some((imbalance, dest)) = Self::make_payout(&nominator.who, nominator_reward) {
 				// Note: this logic does not count payouts for `RewardDestination::None`.
@@ -389,16 +438,41 @@ impl<T: Config> Pallet<T> {
 		T::Reward::on_unbalanced(total_imbalance);
 		debug_assert!(nominator_payout_count <= T::MaxExposurePageSize::get());
 
+		if let Some(caller) = caller {
+			Self::deposit_event(Event::<T>::RewardClaimedBy {
+				caller,
+				validator: stash,
+				era,
+				page,
+			});
+		}
+
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
 	/// Chill a stash account.
-	pub(crate) fn chill_stash(stash: &T::AccountId) {
+	pub(crate) fn chill_stash(stash: &T::AccountId) -> ChillRole {
 		let chilled_as_validator = Self::do_remove_validator(stash);
 		let chilled_as_nominator = Self::do_remove_nominator(stash);
 		if chilled_as_validator || chilled_as_nominator {
+			Self::note_idle(stash);
 			Self::deposit_event(Event::<T>::Chilled { stash: stash.clone() });
 		}
+
+		match (chilled_as_validator, chilled_as_nominator) {
+			(true, _) => ChillRole::WasValidator,
+			(false, true) => ChillRole::WasNominator,
+			(false, false) => ChillRole::WasIdle,
+		}
+	}
+
+	/// Record that `stash` is idle from the active era on, if [`Config::IdleReapEras`] is set and
+	/// `stash` is one of the [`ReapableStashes`].
+	pub(crate) fn note_idle(stash: &T::AccountId) {
+		if T::IdleReapEras::get().is_some() && ReapableStashes::<T>::contains_key(stash) {
+			let active_era = Self::active_era().map(|e| e.index).unwrap_or_default();
+			IdleSince::<T>::insert(stash, active_era);
+		}
 	}
 
 	/// Actually make a payment to a staker. This uses the currency's reward function
@@ -415,22 +489,26 @@ impl<T: Config> Pallet<T> {
 
 		let maybe_imbalance = match dest {
 			RewardDestination::Stash => T::Currency::deposit_into_existing(stash, amount).ok(),
-			RewardDestination::Staked => Self::ledger(Stash(stash.clone()))
-				.and_then(|mut ledger| {
-					ledger.active += amount;
-					ledger.total += amount;
-					let r = T::Currency::deposit_into_existing(stash, amount).ok();
-
-					let _ = ledger
-						.update()
-						.defensive_proof("ledger fetched from storage, so it exists; qed.");
-
-					Ok(r)
-				})
-				.unwrap_or_default(),
+			RewardDestination::Staked => Self::compound_payout(stash, amount),
+			RewardDestination::Split { ref account, portion } => {
+				let staked = portion * amount;
+				let mut imbalance = Self::compound_payout(stash, staked)
+					.unwrap_or_else(PositiveImbalanceOf::<T>::zero);
+				imbalance.subsume(T::Currency::deposit_creating(account, amount - staked));
+				Some(imbalance)
+			},
 			RewardDestination::Account(ref dest_account) =>
 				Some(T::Currency::deposit_creating(&dest_account, amount)),
 			RewardDestination::None => None,
+			RewardDestination::Pool(pool_id) => {
+				let r = T::Currency::deposit_into_existing(stash, amount).ok();
+				if r.is_some() {
+					// the stash may have left the pool since, in which case the reward simply
+					// stays in its free balance.
+					let _ = T::PoolInterface::credit_reward(pool_id, stash, amount);
+				}
+				r
+			},
 			#[allow(deprecated)]
 			RewardDestination::Controller => Self::bonded(stash)
 					.map(|controller| {
@@ -443,6 +521,38 @@ impl<T: Config> Pallet<T> {
 		maybe_imbalance.map(|imbalance| (imbalance, dest))
 	}
 
+	/// Pay `amount` into `stash` and add it to the amount at stake, as far as
+	/// [`Config::MaxLedgerTotal`] allows.
+	///
+	/// Emits [`Event::RewardNotCompounded`] for the part that is only paid out.
+	fn compound_payout(
+		stash: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Option<PositiveImbalanceOf<T>> {
+		Self::ledger(Stash(stash.clone()))
+			.and_then(|mut ledger| {
+				// only compound up to the ledger cap, the rest is paid out as free balance.
+				let compound = T::MaxLedgerTotal::get()
+					.map_or(amount, |cap| amount.min(cap.saturating_sub(ledger.total)));
+				ledger.active += compound;
+				ledger.total += compound;
+				let r = T::Currency::deposit_into_existing(stash, amount).ok();
+
+				let _ = ledger
+					.update()
+					.defensive_proof("ledger fetched from storage, so it exists; qed.");
+
+				if r.is_some() && compound < amount {
+					Self::deposit_event(Event::<T>::RewardNotCompounded {
+						stash: stash.clone(),
+						amount: amount - compound,
+					});
+				}
+				Ok(r)
+			})
+			.unwrap_or_default()
+	}
+
 	/// Plan a new session potentially trigger a new era.
 	fn new_session(
 		session_index: SessionIndex,
@@ -464,10 +574,10 @@ impl<T: Config> Pallet<T> {
 				// Short circuit to `try_trigger_new_era`.
 				Forcing::ForceAlways => (),
 				// Only go to `try_trigger_new_era` if deadline reached.
-				Forcing::NotForcing if era_length >= T::SessionsPerEra::get() => (),
+				Forcing::NotForcing if era_length >= Self::sessions_per_era() => (),
 				_ => {
 					// Either `Forcing::ForceNone`,
-					// or `Forcing::NotForcing if era_length >= T::SessionsPerEra::get()`.
+					// or `Forcing::NotForcing if era_length < Self::sessions_per_era()`.
 					return None
 				},
 			}
@@ -529,6 +639,7 @@ impl<T: Config> Pallet<T> {
 	/// Start a new era. It does:
 	/// * Increment `active_era.index`,
 	/// * reset `active_era.start`,
+	/// * record the start block in `ErasStartBlock`,
 	/// * update `BondedEras` and apply slashes.
 	fn start_era(start_session: SessionIndex) {
 		let active_era = ActiveEra::<T>::mutate(|active_era| {
@@ -540,6 +651,10 @@ impl<T: Config> Pallet<T> {
 			});
 			new_index
 		});
+		ErasStartBlock::<T>::insert(active_era, frame_system::Pallet::<T>::block_number());
+
+		// reward points of the new era are tracked from now on.
+		Self::deposit_event(Event::<T>::EraRewardPointsReset { era: active_era });
 
 		let bonding_duration = T::BondingDuration::get();
 
@@ -565,6 +680,171 @@ impl<T: Config> Pallet<T> {
 		});
 
 		Self::apply_unapplied_slashes(active_era);
+
+		// look for nominations that expire in the new era, starting from the first entry.
+		NominationExpiryCursor::<T>::put(None::<T::AccountId>);
+		// and for payee changes that take effect in the new era.
+		PendingPayeeCursor::<T>::put(None::<T::AccountId>);
+
+		// pay out the era that just ended. A previous payout that is still ongoing is abandoned,
+		// its remaining rewards can still be claimed manually.
+		if T::AutoPayout::get() {
+			if let Some(ended_era) = active_era.checked_sub(1) {
+				AutoPayoutCursor::<T>::put((ended_era, None::<T::AccountId>));
+			}
+		}
+
+		T::OnEraStart::on_era_start(active_era);
+	}
+
+	/// Chill up to `T::NominationExpiryScanLimit` nominators whose nominations have expired,
+	/// continuing the scan of [`NominationExpiry`] started at the beginning of the active era.
+	///
+	/// Returns the weight consumed.
+	pub(crate) fn chill_expired_nominators() -> Weight {
+		let cursor = match NominationExpiryCursor::<T>::get() {
+			Some(cursor) => cursor,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let active_era = Self::active_era().map(|e| e.index).unwrap_or_default();
+		let limit = T::NominationExpiryScanLimit::get();
+
+		let batch: Vec<_> = match cursor {
+			Some(last) =>
+				NominationExpiry::<T>::iter_from(NominationExpiry::<T>::hashed_key_for(last)),
+			None => NominationExpiry::<T>::iter(),
+		}
+		.take(limit as usize)
+		.collect();
+
+		let mut weight = T::DbWeight::get().reads_writes(2 + batch.len() as u64, 1);
+		for (stash, expiry_era) in batch.iter() {
+			if *expiry_era <= active_era {
+				Self::chill_stash(stash);
+				weight.saturating_accrue(T::WeightInfo::chill());
+			}
+		}
+
+		if (batch.len() as u32) < limit {
+			NominationExpiryCursor::<T>::kill();
+		} else {
+			NominationExpiryCursor::<T>::put(batch.last().map(|(stash, _)| stash.clone()));
+		}
+
+		weight
+	}
+
+	/// Apply the payee changes of up to `T::PendingPayeeScanLimit` entries of [`PendingPayee`]
+	/// that take effect in the active era, continuing the scan started at the beginning of the
+	/// active era.
+	///
+	/// Returns the weight consumed.
+	pub(crate) fn apply_pending_payees() -> Weight {
+		let cursor = match PendingPayeeCursor::<T>::get() {
+			Some(cursor) => cursor,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let active_era = Self::active_era().map(|e| e.index).unwrap_or_default();
+		let limit = T::PendingPayeeScanLimit::get();
+
+		let batch: Vec<_> = match cursor {
+			Some(last) => PendingPayee::<T>::iter_from(PendingPayee::<T>::hashed_key_for(last)),
+			None => PendingPayee::<T>::iter(),
+		}
+		.take(limit as usize)
+		.collect();
+
+		let mut weight = T::DbWeight::get().reads_writes(2 + batch.len() as u64, 1);
+		for (stash, (era, payee)) in batch.iter() {
+			if *era <= active_era {
+				if let Ok(ledger) = Self::ledger(Stash(stash.clone())) {
+					let _ = Self::do_set_payee(ledger, payee.clone()).defensive();
+				}
+				PendingPayee::<T>::remove(stash);
+				weight.saturating_accrue(T::WeightInfo::set_payee());
+			}
+		}
+
+		if (batch.len() as u32) < limit {
+			PendingPayeeCursor::<T>::kill();
+		} else {
+			PendingPayeeCursor::<T>::put(batch.last().map(|(stash, _)| stash.clone()));
+		}
+
+		weight
+	}
+
+	/// Set the payee of `ledger` immediately, dropping any payee change scheduled for it.
+	pub(crate) fn do_set_payee(
+		ledger: StakingLedger<T>,
+		payee: RewardDestination<T::AccountId>,
+	) -> Result<(), Error<T>> {
+		let stash = ledger.stash.clone();
+		ledger.set_payee(payee.clone())?;
+		PendingPayee::<T>::remove(&stash);
+
+		Self::deposit_event(Event::<T>::PayeeSet { stash, payee });
+		Ok(())
+	}
+
+	/// Attempt up to `T::MaxAutoPayoutsPerBlock` payouts of the era in [`AutoPayoutCursor`],
+	/// paying all unclaimed pages of one validator after the other.
+	///
+	/// Returns the weight consumed.
+	pub(crate) fn auto_payout_stakers() -> Weight {
+		let (era, last) = match AutoPayoutCursor::<T>::get() {
+			Some(cursor) => cursor,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let limit = T::MaxAutoPayoutsPerBlock::get();
+		let max_payout_weight =
+			T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get());
+
+		// every validator needs at least one attempt, so there is no point in reading more.
+		let validators: Vec<_> = match last.as_ref() {
+			Some(last) => ErasStakersOverview::<T>::iter_key_prefix_from(
+				era,
+				ErasStakersOverview::<T>::hashed_key_for(era, last),
+			),
+			None => ErasStakersOverview::<T>::iter_key_prefix(era),
+		}
+		.take(limit as usize)
+		.collect();
+
+		let mut weight = T::DbWeight::get().reads_writes(1 + validators.len() as u64, 1);
+		let mut attempts = 0u32;
+		let mut last_paid = last;
+		for stash in validators.iter() {
+			// pay all pages of `stash`, until it errors with `AlreadyClaimed` or similar.
+			loop {
+				if attempts >= limit {
+					// resume with `stash` in the next block.
+					AutoPayoutCursor::<T>::put((era, last_paid));
+					return weight
+				}
+				attempts.saturating_inc();
+
+				match Self::do_payout_stakers(stash.clone(), era, None) {
+					Ok(info) =>
+						weight.saturating_accrue(info.actual_weight.unwrap_or(max_payout_weight)),
+					Err(e) => {
+						weight.saturating_accrue(
+							e.post_info.actual_weight.unwrap_or(max_payout_weight),
+						);
+						break
+					},
+				}
+			}
+			last_paid = Some(stash.clone());
+		}
+
+		if (validators.len() as u32) < limit {
+			AutoPayoutCursor::<T>::kill();
+		} else {
+			AutoPayoutCursor::<T>::put((era, last_paid));
+		}
+
+		weight
 	}
 
 	/// Compute payout for era.
@@ -581,27 +861,43 @@ impl<T: Config> Pallet<T> {
 			let (validator_payout, remainder) =
 				T::EraPayout::era_payout(staked, issuance, era_duration);
 
-			let total_payout = validator_payout.saturating_add(remainder);
-			let max_staked_rewards =
-				MaxStakedRewards::<T>::get().unwrap_or(Percent::from_percent(100));
+			// the whole payout must be mintable, otherwise nothing of it is applied.
+			let maybe_total_payout = validator_payout
+				.checked_add(&remainder)
+				.filter(|total_payout| issuance.checked_add(total_payout).is_some());
 
-			// apply cap to validators payout and add difference to remainder.
-			let validator_payout = validator_payout.min(max_staked_rewards * total_payout);
-			let remainder = total_payout.saturating_sub(validator_payout);
+			if let Some(total_payout) = maybe_total_payout {
+				let max_staked_rewards =
+					MaxStakedRewards::<T>::get().unwrap_or(Percent::from_percent(100));
 
-			Self::deposit_event(Event::<T>::EraPaid {
-				era_index: active_era.index,
-				validator_payout,
-				remainder,
-			});
+				// apply cap to validators payout and add difference to remainder.
+				let validator_payout = validator_payout.min(max_staked_rewards * total_payout);
+				let remainder = total_payout.saturating_sub(validator_payout);
 
-			// Set ending era reward.
-			<ErasValidatorReward<T>>::insert(&active_era.index, validator_payout);
-			T::RewardRemainder::on_unbalanced(T::Currency::issue(remainder));
+				Self::deposit_event(Event::<T>::EraPaid {
+					era_index: active_era.index,
+					validator_payout,
+					remainder,
+					total_staked: staked,
+				});
+
+				// Set ending era reward.
+				<ErasValidatorReward<T>>::insert(&active_era.index, validator_payout);
+				T::RewardRemainder::on_unbalanced(T::Currency::issue(remainder));
+			} else {
+				log!(
+					warn,
+					"reward of era {:?} cannot be minted without overflowing the total issuance",
+					active_era.index
+				);
+				Self::deposit_event(Event::<T>::EraRewardMintFailed { era: active_era.index });
+			}
 
 			// Clear disabled validators.
 			<DisabledValidators<T>>::kill();
 		}
+
+		T::OnEraEnd::on_era_end(active_era.index);
 	}
 
 	/// Plan a new era.
@@ -626,6 +922,11 @@ impl<T: Config> Pallet<T> {
 		});
 		ErasStartSessionIndex::<T>::insert(&new_planned_era, &start_session_index);
 
+		// A new number of sessions per era only applies from the start of an era.
+		if let Some(maybe_override) = PendingSessionsPerEraOverride::<T>::take() {
+			SessionsPerEraOverride::<T>::set(maybe_override);
+		}
+
 		// Clean old era information.
 		if let Some(old_era) = new_planned_era.checked_sub(T::HistoryDepth::get() + 1) {
 			Self::clear_era_information(old_era);
@@ -692,7 +993,11 @@ impl<T: Config> Pallet<T> {
 			return None
 		}
 
-		Self::deposit_event(Event::StakersElected);
+		let total_stake =
+			exposures.iter().fold(Zero::zero(), |total: BalanceOf<T>, (_, exposure)| {
+				total.saturating_add(exposure.total)
+			});
+		Self::deposit_event(Event::StakersElected { count: exposures.len() as u32, total_stake });
 		Some(Self::trigger_new_era(start_session_index, exposures))
 	}
 
@@ -760,10 +1065,16 @@ impl<T: Config> Pallet<T> {
 				let mut others = Vec::with_capacity(support.voters.len());
 				let mut own: BalanceOf<T> = Zero::zero();
 				let mut total: BalanceOf<T> = Zero::zero();
+				// nominators backing the validator with less than its minimum are left out.
+				let min_nominator_stake = ValidatorNominationFilter::<T>::get(&validator);
 				support
 					.voters
 					.into_iter()
 					.map(|(nominator, weight)| (nominator, to_currency(weight)))
+					.filter(|(nominator, stake)| {
+						*nominator == validator ||
+							min_nominator_stake.map_or(true, |min| *stake >= min)
+					})
 					.for_each(|(nominator, stake)| {
 						if nominator == validator {
 							own = own.saturating_add(stake);
@@ -796,6 +1107,10 @@ impl<T: Config> Pallet<T> {
 
 		Self::do_remove_validator(&stash);
 		Self::do_remove_nominator(&stash);
+		IdleSince::<T>::remove(&stash);
+		ReapableStashes::<T>::remove(&stash);
+		LastCommissionUpdate::<T>::remove(&stash);
+		ValidatorNominationFilter::<T>::remove(&stash);
 
 		frame_system::Pallet::<T>::dec_consumers(&stash);
 
@@ -823,6 +1138,7 @@ impl<T: Config> Pallet<T> {
 		<ErasRewardPoints<T>>::remove(era_index);
 		<ErasTotalStake<T>>::remove(era_index);
 		ErasStartSessionIndex::<T>::remove(era_index);
+		ErasStartBlock::<T>::remove(era_index);
 	}
 
 	/// Apply previously-unapplied slashes on the beginning of a new era, after a delay.
@@ -834,9 +1150,11 @@ impl<T: Config> Pallet<T> {
 			era_slashes.len(),
 			active_era,
 		);
+		let slash_era = active_era.saturating_sub(T::SlashDeferDuration::get());
+		// slashes are deferred to the era after the offence era plus the defer duration.
+		let offence_era = slash_era.saturating_sub(One::one());
 		for slash in era_slashes {
-			let slash_era = active_era.saturating_sub(T::SlashDeferDuration::get());
-			slashing::apply_slash::<T>(slash, slash_era);
+			slashing::apply_slash::<T>(slash, slash_era, offence_era);
 		}
 	}
 
@@ -1063,6 +1381,7 @@ impl<T: Config> Pallet<T> {
 				.defensive_unwrap_or_default();
 		}
 		Nominators::<T>::insert(who, nominations);
+		IdleSince::<T>::remove(who);
 
 		debug_assert_eq!(
 			Nominators::<T>::count() + Validators::<T>::count(),
@@ -1081,6 +1400,7 @@ impl<T: Config> Pallet<T> {
 	pub fn do_remove_nominator(who: &T::AccountId) -> bool {
 		let outcome = if Nominators::<T>::contains_key(who) {
 			Nominators::<T>::remove(who);
+			NominationExpiry::<T>::remove(who);
 			let _ = T::VoterList::on_remove(who).defensive();
 			true
 		} else {
@@ -1109,6 +1429,7 @@ impl<T: Config> Pallet<T> {
 				.defensive_unwrap_or_default();
 		}
 		Validators::<T>::insert(who, prefs);
+		IdleSince::<T>::remove(who);
 
 		debug_assert_eq!(
 			Nominators::<T>::count() + Validators::<T>::count(),
@@ -1125,7 +1446,12 @@ impl<T: Config> Pallet<T> {
 	/// wrong.
 	pub fn do_remove_validator(who: &T::AccountId) -> bool {
 		let outcome = if Validators::<T>::contains_key(who) {
-			Validators::<T>::remove(who);
+			let commission = Validators::<T>::take(who).commission;
+			// remember the commission, so that validating again is rate limited as well.
+			LastCommissionUpdate::<T>::mutate(who, |update| match update {
+				Some(update) => update.commission = commission,
+				None => *update = Some(CommissionUpdate { commission, raised_in: None }),
+			});
 			let _ = T::VoterList::on_remove(who).defensive();
 			true
 		} else {
@@ -1161,6 +1487,438 @@ impl<T: Config> Pallet<T> {
 	) -> Exposure<T::AccountId, BalanceOf<T>> {
 		EraInfo::<T>::get_full_exposure(era, account)
 	}
+
+	/// Whether `stash` is eligible for fast unstaking.
+	///
+	/// A bonded stash is eligible if it has not been exposed in any of the last
+	/// `T::BondingDuration` eras and has no pending (unapplied) slashes.
+	pub fn fast_unstake_eligible(stash: &T::AccountId) -> bool {
+		if !StakingLedger::<T>::is_bonded(Stash(stash.clone())) {
+			return false
+		}
+
+		let current_era = CurrentEra::<T>::get().unwrap_or(0);
+		let exposed = (current_era.saturating_sub(T::BondingDuration::get())..=current_era)
+			.any(|era| <Self as StakingInterface>::is_exposed_in_era(stash, &era));
+
+		let pending_slash = UnappliedSlashes::<T>::iter().any(|(_, slashes)| {
+			slashes.iter().any(|slash| {
+				slash.validator == *stash || slash.others.iter().any(|(n, _)| n == stash)
+			})
+		});
+
+		!exposed && !pending_slash
+	}
+
+	/// The era at the start of which a deferred slash for an offence committed in `era` is
+	/// applied, or `None` if slashes are applied immediately.
+	///
+	/// Deferred slashes are applied once `T::SlashDeferDuration` full eras have passed after
+	/// `era`. Note that offences below `T::SlashDeferThreshold` are never deferred.
+	pub fn slash_defer_window(era: EraIndex) -> Option<EraIndex> {
+		let slash_defer_duration = T::SlashDeferDuration::get();
+		if slash_defer_duration.is_zero() {
+			return None
+		}
+
+		Some(era.saturating_add(slash_defer_duration).saturating_add(One::one()))
+	}
+
+	/// Whether `who` is restricted from bonding, see [`RestrictedAccounts`].
+	pub fn is_restricted(who: &T::AccountId) -> bool {
+		RestrictedAccounts::<T>::contains_key(who)
+	}
+
+	/// Whether `stash` is one of the [`Invulnerables`], which are never slashed.
+	pub fn is_invulnerable(stash: &T::AccountId) -> bool {
+		Invulnerables::<T>::get().contains(stash)
+	}
+
+	/// The number of bonded stashes, whether they are validating, nominating or idle.
+	pub fn bonded_accounts_count() -> u32 {
+		BondedCount::<T>::get()
+	}
+
+	/// The total balance being unlocked across all stakers, i.e. pending withdrawal.
+	pub fn total_unlocking_across_all_stakers() -> BalanceOf<T> {
+		TotalUnlocking::<T>::get()
+	}
+
+	/// Whether `stash` is bonded but neither validating nor nominating.
+	pub fn is_chilled(stash: &T::AccountId) -> bool {
+		StakingLedger::<T>::is_bonded(Stash(stash.clone())) &&
+			!Validators::<T>::contains_key(stash) &&
+			!Nominators::<T>::contains_key(stash)
+	}
+
+	/// All exposure pages of `validator` in `era`, in page order.
+	///
+	/// For older non-paged exposures, the clipped exposure is returned as a single page.
+	pub fn exposure_pages(
+		era: EraIndex,
+		validator: &T::AccountId,
+	) -> Vec<ExposurePage<T::AccountId, BalanceOf<T>>> {
+		match ErasStakersOverview::<T>::get(era, validator) {
+			Some(overview) => (0..overview.page_count)
+				.filter_map(|page| ErasStakersPaged::<T>::get((era, validator, page)))
+				.collect(),
+			None => Self::legacy_clipped_exposure(era, validator)
+				.map(|clipped| vec![PagedExposure::from_clipped(clipped).exposure_page])
+				.unwrap_or_default(),
+		}
+	}
+
+	/// The non-paged clipped exposure of `validator` in `era`, as stored in
+	/// [`ErasStakersClipped`].
+	///
+	/// Returns `None` if no clipped exposure exists, e.g. for eras backed by paged exposures.
+	// exists for backward compatibility and can be removed as part of #13034
+	pub fn legacy_clipped_exposure(
+		era: EraIndex,
+		validator: &T::AccountId,
+	) -> Option<Exposure<T::AccountId, BalanceOf<T>>> {
+		ErasStakersClipped::<T>::contains_key(era, validator)
+			.then(|| ErasStakersClipped::<T>::get(era, validator))
+	}
+
+	/// The number of sessions per era, taking [`SessionsPerEraOverride`] into account.
+	pub fn sessions_per_era() -> SessionIndex {
+		SessionsPerEraOverride::<T>::get().unwrap_or_else(T::SessionsPerEra::get)
+	}
+
+	/// The reward `nominator` will receive from backing `validator` in `era`, after the
+	/// commission of the validator is deducted.
+	///
+	/// Returns `None` if the reward of `era` is not known yet, if `nominator` is not exposed to
+	/// `validator` in `era` or if the page it is exposed in has already been claimed.
+	pub fn validator_reward_share(
+		era: EraIndex,
+		validator: &T::AccountId,
+		nominator: &T::AccountId,
+	) -> Option<BalanceOf<T>> {
+		let ledger = Self::ledger(Stash(validator.clone())).ok()?;
+		let (validator_leftover_payout, _) = Self::validator_era_payout_split(era, validator)?;
+
+		let (page, value, total) =
+			(0..EraInfo::<T>::get_page_count(era, validator)).find_map(|page| {
+				let exposure = EraInfo::<T>::get_paged_exposure(era, validator, page)?;
+				let value = exposure.others().iter().find(|i| &i.who == nominator)?.value;
+				Some((page, value, exposure.total()))
+			})?;
+
+		if EraInfo::<T>::is_rewards_claimed_with_legacy_fallback(era, &ledger, validator, page) {
+			return None
+		}
+
+		Some(Self::exposure_payout(value, total, validator_leftover_payout))
+	}
+
+	/// The total stake exposed in `era`, i.e. the own stake of all elected validators plus the
+	/// stake of their nominators, as recorded in [`ErasTotalStake`].
+	///
+	/// Stake of nominators that were not elected, or that exceeded
+	/// [`Config::MaxExposurePageSize`] in legacy exposures, is not included. Returns `None` if
+	/// `era` is not within history depth.
+	pub fn total_exposed_stake(era: EraIndex) -> Option<BalanceOf<T>> {
+		Self::era_within_history(era).then(|| ErasTotalStake::<T>::get(era))
+	}
+
+	/// Whether `era` is not in the future and within [`Config::HistoryDepth`] of the current era.
+	pub(crate) fn era_within_history(era: EraIndex) -> bool {
+		CurrentEra::<T>::get().map_or(false, |current_era| {
+			era <= current_era && era >= current_era.saturating_sub(T::HistoryDepth::get())
+		})
+	}
+
+	/// The total stake backing `validator` in the active era, or zero if it is not exposed in the
+	/// active era.
+	pub fn current_total_stake(validator: &T::AccountId) -> BalanceOf<T> {
+		Self::active_era()
+			.and_then(|era| ErasStakersOverview::<T>::get(era.index, validator))
+			.map(|overview| overview.total)
+			.unwrap_or_default()
+	}
+
+	/// The timestamp at which the active era started, in milliseconds.
+	///
+	/// Returns `None` if there is no active era yet, or if the start of the active era has not
+	/// been recorded yet, which happens when the block in which the era started is finalized.
+	pub fn active_era_start() -> Option<u64> {
+		Self::active_era().and_then(|era| era.start)
+	}
+
+	/// Whether `stash` is exposed, as a validator or as a nominator, in any of the eras from
+	/// `current_era - BondingDuration` up to `current_era`.
+	///
+	/// This iterates over all exposures of these eras.
+	pub fn is_exposed_in_any_recent_era(stash: &T::AccountId) -> bool {
+		let Some(current_era) = CurrentEra::<T>::get() else { return false };
+		let first_era = current_era.saturating_sub(T::BondingDuration::get());
+
+		(first_era..=current_era)
+			.any(|era| <Self as StakingInterface>::is_exposed_in_era(stash, &era))
+	}
+
+	/// An estimate of the stake a validator needs to stay elected, i.e. the lowest total stake
+	/// among the validators elected in the active era.
+	///
+	/// Returns `None` if no validator is exposed in the active era. This iterates over all
+	/// validators of the active era.
+	pub fn minimum_stake_to_elect() -> Option<BalanceOf<T>> {
+		let active_era = Self::active_era()?.index;
+		ErasStakersOverview::<T>::iter_prefix_values(active_era)
+			.map(|overview| overview.total)
+			.min()
+	}
+
+	/// The validators of the current session that are disabled, e.g. as a result of an offence.
+	pub fn disabled_validators() -> Vec<T::AccountId> {
+		let validators = T::SessionInterface::validators();
+		T::SessionInterface::disabled_validators()
+			.into_iter()
+			.filter_map(|index| validators.get(index as usize).cloned())
+			.collect()
+	}
+
+	/// The amount of `stash` that is held by this pallet for staking.
+	///
+	/// Staked funds are locked under the staking lock identifier, so any other lock or reserve
+	/// on the account is not included. This is always zero for virtual stakers, whose funds are
+	/// held by another pallet.
+	pub fn staking_hold_amount(stash: &T::AccountId) -> BalanceOf<T> {
+		T::Currency::balance_locked(crate::STAKING_ID, stash)
+	}
+
+	/// The total amount of `stash` that will have unlocked by `era`, i.e. the sum of all unlocking
+	/// chunks of the ledger that mature in or before `era`.
+	///
+	/// Returns zero if `stash` is not bonded.
+	pub fn unlocking_total_at_era(stash: &T::AccountId, era: EraIndex) -> BalanceOf<T> {
+		Self::ledger(Stash(stash.clone()))
+			.map(|ledger| {
+				ledger
+					.unlocking
+					.iter()
+					.filter(|chunk| chunk.era <= era)
+					.fold(Zero::zero(), |total: BalanceOf<T>, chunk| {
+						total.saturating_add(chunk.value)
+					})
+			})
+			.unwrap_or_default()
+	}
+
+	/// The era in which the current nominations of `nominator` were submitted, or `None` if it
+	/// is not a nominator.
+	///
+	/// Slashes of eras before this one do not affect the nominations.
+	pub fn nomination_submitted_in(nominator: &T::AccountId) -> Option<EraIndex> {
+		Nominators::<T>::get(nominator).map(|nominations| nominations.submitted_in)
+	}
+
+	/// Checks whether `who` could successfully [`Call::nominate`] `targets`, without changing any
+	/// state.
+	///
+	/// `who` is the stash account. The checks are the same as in [`Call::nominate`], except for
+	/// the lookup of the targets.
+	pub fn can_nominate(who: &T::AccountId, targets: &[T::AccountId]) -> Result<(), Error<T>> {
+		let ledger = Self::ledger(Stash(who.clone()))?;
+		ensure!(ledger.active >= MinNominatorBond::<T>::get(), Error::<T>::InsufficientBond);
+
+		// Only check limits if they are not already a nominator.
+		if !Nominators::<T>::contains_key(who) {
+			if let Some(max_nominators) = MaxNominatorsCount::<T>::get() {
+				ensure!(Nominators::<T>::count() < max_nominators, Error::<T>::TooManyNominators);
+			}
+		}
+
+		ensure!(!targets.is_empty(), Error::<T>::EmptyTargets);
+		ensure!(
+			targets.len() <= Self::api_nominations_quota(ledger.active) as usize,
+			Error::<T>::TooManyTargets
+		);
+
+		let old = Nominators::<T>::get(who).map_or_else(Vec::new, |x| x.targets.into_inner());
+		ensure!(
+			targets.iter().all(|t| old.contains(t) || !Validators::<T>::get(t).blocked),
+			Error::<T>::BadTarget
+		);
+		ensure!(
+			targets.len() <= MaxNominationsOf::<T>::get() as usize,
+			Error::<T>::TooManyNominators
+		);
+
+		Ok(())
+	}
+
+	/// The additional bond `stash` needs to reach [`MinNominatorBond`], or zero if its active
+	/// bond already meets it.
+	///
+	/// Stashes that are not bonded need the full [`MinNominatorBond`].
+	pub fn minimum_nominator_bond_effective(stash: &T::AccountId) -> BalanceOf<T> {
+		let active = Self::ledger(Stash(stash.clone())).map_or_else(|_| Zero::zero(), |l| l.active);
+		MinNominatorBond::<T>::get().saturating_sub(active)
+	}
+
+	/// Checks whether `who` could successfully [`Call::validate`] with `prefs`, without changing
+	/// any state.
+	///
+	/// `who` is the stash account. The checks are the same as in [`Call::validate`].
+	pub fn can_validate(who: &T::AccountId, prefs: &ValidatorPrefs) -> Result<(), Error<T>> {
+		let ledger = Self::ledger(Stash(who.clone()))?;
+		ensure!(ledger.active >= MinValidatorBond::<T>::get(), Error::<T>::InsufficientBond);
+		ensure!(prefs.commission >= MinCommission::<T>::get(), Error::<T>::CommissionTooLow);
+
+		// Only check limits if they are not already a validator.
+		let old_commission = if !Validators::<T>::contains_key(who) {
+			if let Some(max_validators) = MaxValidatorsCount::<T>::get() {
+				ensure!(Validators::<T>::count() < max_validators, Error::<T>::TooManyValidators);
+			}
+			LastCommissionUpdate::<T>::get(who).map(|u| u.commission)
+		} else {
+			Some(Validators::<T>::get(who).commission)
+		};
+		if let Some(old_commission) = old_commission.filter(|old| prefs.commission > *old) {
+			Self::ensure_commission_increase_allowed(who, old_commission, prefs.commission)?;
+		}
+
+		Ok(())
+	}
+
+	/// The weight of [`Call::validate`], including the [`CommissionChangeRateLimit`] checks.
+	pub(crate) fn validate_weight() -> Weight {
+		T::WeightInfo::validate().saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+
+	/// Checks that validator `stash` can raise its commission from `old` to `new` in the active
+	/// era, see [`CommissionChangeRateLimit`].
+	///
+	/// `old` is the current commission of `stash`, or the last one it validated with if it is
+	/// chilled.
+	pub(crate) fn ensure_commission_increase_allowed(
+		stash: &T::AccountId,
+		old: Perbill,
+		new: Perbill,
+	) -> Result<(), Error<T>> {
+		let Some(limit) = CommissionChangeRateLimit::<T>::get() else { return Ok(()) };
+		let era = Self::active_era().map(|e| e.index).unwrap_or(0);
+
+		ensure!(
+			new.saturating_sub(old) <= limit.max_increase,
+			Error::<T>::CommissionIncreaseTooLarge
+		);
+		ensure!(
+			LastCommissionUpdate::<T>::get(stash)
+				.and_then(|u| u.raised_in)
+				.map_or(true, |last| era >= last.saturating_add(limit.min_delay)),
+			Error::<T>::CommissionUpdateTooFrequent
+		);
+		Ok(())
+	}
+
+	/// The staking configurations that [`Call::set_staking_configs`] would produce with the given
+	/// operations, without changing any state.
+	///
+	/// [`Call::set_staking_configs`] applies every operation as is, hence none of them can be
+	/// rejected once the origin is checked.
+	pub fn preview_staking_configs(
+		min_nominator_bond: ConfigOp<BalanceOf<T>>,
+		min_validator_bond: ConfigOp<BalanceOf<T>>,
+		max_nominator_count: ConfigOp<u32>,
+		max_validator_count: ConfigOp<u32>,
+		chill_threshold: ConfigOp<Percent>,
+		min_commission: ConfigOp<Perbill>,
+		max_staked_rewards: ConfigOp<Percent>,
+	) -> StakingConfigPreview<BalanceOf<T>> {
+		macro_rules! config_op_preview {
+			($storage:ty, $op:ident) => {
+				match $op {
+					ConfigOp::Noop => <$storage>::get(),
+					ConfigOp::Set(v) => v.into(),
+					ConfigOp::Remove => Default::default(),
+				}
+			};
+		}
+
+		StakingConfigPreview {
+			min_nominator_bond: config_op_preview!(MinNominatorBond<T>, min_nominator_bond),
+			min_validator_bond: config_op_preview!(MinValidatorBond<T>, min_validator_bond),
+			max_nominator_count: config_op_preview!(MaxNominatorsCount<T>, max_nominator_count),
+			max_validator_count: config_op_preview!(MaxValidatorsCount<T>, max_validator_count),
+			chill_threshold: config_op_preview!(ChillThreshold<T>, chill_threshold),
+			min_commission: config_op_preview!(MinCommission<T>, min_commission),
+			max_staked_rewards: config_op_preview!(MaxStakedRewards<T>, max_staked_rewards),
+		}
+	}
+
+	/// The account that receives the staking rewards of `stash`, as paid out by `make_payout`.
+	///
+	/// Returns `None` if `stash` is not bonded or if its reward destination is
+	/// [`RewardDestination::None`]. For [`RewardDestination::Split`], this is the account receiving
+	/// the part of the rewards that is not staked.
+	pub fn resolved_payee(stash: &T::AccountId) -> Option<T::AccountId> {
+		match Self::payee(Stash(stash.clone()))? {
+			RewardDestination::Staked | RewardDestination::Stash | RewardDestination::Pool(_) =>
+				Some(stash.clone()),
+			RewardDestination::Account(account) => Some(account),
+			RewardDestination::Split { account, .. } => Some(account),
+			RewardDestination::None => None,
+			#[allow(deprecated)]
+			RewardDestination::Controller => Self::bonded(stash),
+		}
+	}
+
+	/// Splits the reward of `validator` in `era` into the part that is shared among its exposure
+	/// and its commission, the same way as `do_payout_stakers_by_page`.
+	///
+	/// Returns `None` if the reward of `era` is not known yet.
+	fn validator_era_payout_split(
+		era: EraIndex,
+		validator: &T::AccountId,
+	) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+		let era_payout = ErasValidatorReward::<T>::get(era)?;
+
+		let era_reward_points = ErasRewardPoints::<T>::get(era);
+		let validator_reward_points =
+			era_reward_points.individual.get(validator).copied().unwrap_or_else(Zero::zero);
+		if validator_reward_points.is_zero() {
+			return Some((Zero::zero(), Zero::zero()))
+		}
+
+		Some(Self::split_validator_payout(
+			era_payout,
+			validator_reward_points,
+			era_reward_points.total,
+			EraInfo::<T>::get_validator_commission(era, validator),
+		))
+	}
+
+	/// Splits the share of `era_payout` that a validator with `validator_reward_points` earned
+	/// into the part that is shared among its exposure and its `commission`.
+	fn split_validator_payout(
+		era_payout: BalanceOf<T>,
+		validator_reward_points: RewardPoint,
+		total_reward_points: RewardPoint,
+		commission: Perbill,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		// This is how much validator + nominators are entitled to.
+		let validator_total_payout =
+			Perbill::from_rational(validator_reward_points, total_reward_points) * era_payout;
+		let validator_total_commission_payout = commission * validator_total_payout;
+		let validator_leftover_payout =
+			validator_total_payout.defensive_saturating_sub(validator_total_commission_payout);
+
+		(validator_leftover_payout, validator_total_commission_payout)
+	}
+
+	/// The part of `leftover_payout` of a validator that is paid for `stake` out of the `total`
+	/// stake of its exposure.
+	fn exposure_payout(
+		stake: BalanceOf<T>,
+		total: BalanceOf<T>,
+		leftover_payout: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		Perbill::from_rational(stake, total) * leftover_payout
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -1185,6 +1943,344 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		EraInfo::<T>::pending_rewards(era, &account)
 	}
+
+	/// Returns the oldest era within history depth for which `validator` still has rewards to
+	/// claim, or `None` if all of them are claimed.
+	pub fn api_next_payout_era(validator: T::AccountId) -> Option<EraIndex> {
+		let current_era = CurrentEra::<T>::get()?;
+		(current_era.saturating_sub(T::HistoryDepth::get())..=current_era).find(|era| {
+			ErasValidatorReward::<T>::contains_key(era) &&
+				EraInfo::<T>::pending_rewards(*era, &validator)
+		})
+	}
+
+	/// Returns all the eras within history depth for which `validator` still has rewards to
+	/// claim, oldest first.
+	///
+	/// This checks every era within history depth and is therefore only meant to be used
+	/// off-chain.
+	pub fn api_pending_payout_eras(validator: T::AccountId) -> Vec<EraIndex> {
+		let Some(current_era) = CurrentEra::<T>::get() else { return Vec::new() };
+		(current_era.saturating_sub(T::HistoryDepth::get())..=current_era)
+			.filter(|era| {
+				ErasValidatorReward::<T>::contains_key(era) &&
+					EraInfo::<T>::pending_rewards(*era, &validator)
+			})
+			.collect()
+	}
+
+	/// Returns an aggregated summary of `era`, or `None` if the era is not within history depth.
+	pub fn api_era_summary(era: EraIndex) -> Option<EraSummary<BalanceOf<T>>> {
+		if !Self::era_within_history(era) {
+			return None
+		}
+
+		Some(EraSummary {
+			total_stake: ErasTotalStake::<T>::get(era),
+			validator_reward: ErasValidatorReward::<T>::get(era),
+			total_reward_points: ErasRewardPoints::<T>::get(era).total,
+			validator_count: ErasStakersOverview::<T>::iter_prefix(era).count() as u32,
+		})
+	}
+
+	/// Returns the number of validators elected for the current era, alongside the desired
+	/// validator count, the minimum validator count and the maximum number of election winners.
+	pub fn api_validator_count_status() -> (u32, u32, u32, u32) {
+		let elected = CurrentEra::<T>::get()
+			.map(|era| ErasStakersOverview::<T>::iter_prefix(era).count() as u32)
+			.unwrap_or_default();
+
+		(
+			elected,
+			ValidatorCount::<T>::get(),
+			MinimumValidatorCount::<T>::get(),
+			<T::ElectionProvider as ElectionProviderBase>::MaxWinners::get(),
+		)
+	}
+
+	/// Returns the page of the exposure of `validator` in `era` that contains `nominator`, if
+	/// any.
+	pub fn api_exposure_page_of_nominator(
+		era: EraIndex,
+		validator: T::AccountId,
+		nominator: T::AccountId,
+	) -> Option<Page> {
+		(0..EraInfo::<T>::get_page_count(era, &validator)).find(|page| {
+			EraInfo::<T>::get_paged_exposure(era, &validator, *page)
+				.map_or(false, |exposure| exposure.others().iter().any(|i| i.who == nominator))
+		})
+	}
+
+	/// Returns the total reward that can still be paid out for `validator` and its nominators in
+	/// `era`, summed across all the exposure pages that are not claimed yet.
+	pub fn api_total_rewardable(era: EraIndex, validator: T::AccountId) -> BalanceOf<T> {
+		let (Some((validator_leftover_payout, validator_total_commission_payout)), Ok(ledger)) = (
+			Self::validator_era_payout_split(era, &validator),
+			Self::ledger(Stash(validator.clone())),
+		) else {
+			return Zero::zero()
+		};
+
+		(0..EraInfo::<T>::get_page_count(era, &validator))
+			.filter(|page| {
+				!EraInfo::<T>::is_rewards_claimed_with_legacy_fallback(
+					era, &ledger, &validator, *page,
+				)
+			})
+			.filter_map(|page| EraInfo::<T>::get_paged_exposure(era, &validator, page))
+			.fold(Zero::zero(), |total: BalanceOf<T>, exposure| {
+				let validator_staking_payout = Self::exposure_payout(
+					exposure.own(),
+					exposure.total(),
+					validator_leftover_payout,
+				);
+				let validator_commission_payout =
+					Perbill::from_rational(exposure.page_total(), exposure.total()) *
+						validator_total_commission_payout;
+				let nominators_payout =
+					exposure.others().iter().fold(Zero::zero(), |acc: BalanceOf<T>, nominator| {
+						acc.saturating_add(Self::exposure_payout(
+							nominator.value,
+							exposure.total(),
+							validator_leftover_payout,
+						))
+					});
+
+				total
+					.saturating_add(validator_staking_payout)
+					.saturating_add(validator_commission_payout)
+					.saturating_add(nominators_payout)
+			})
+	}
+
+	/// Returns all the accounts that are restricted from bonding.
+	///
+	/// This iterates over the whole of [`RestrictedAccounts`] and is therefore only meant to be
+	/// used off-chain.
+	pub fn api_restricted_accounts() -> Vec<T::AccountId> {
+		RestrictedAccounts::<T>::iter_keys().collect()
+	}
+
+	/// Returns the approval stake of `validator`, that is its own stake plus the full stake of all
+	/// the nominators that currently nominate it.
+	///
+	/// This iterates over all [`Nominators`] and is therefore only meant to be used off-chain.
+	pub fn api_approval_stake(validator: T::AccountId) -> BalanceOf<T> {
+		Nominators::<T>::iter()
+			.filter(|(_, nominations)| nominations.targets.contains(&validator))
+			.fold(Self::slashable_balance_of(&validator), |approval, (nominator, _)| {
+				approval.saturating_add(Self::slashable_balance_of(&nominator))
+			})
+	}
+
+	/// Returns the number of stashes that intend to validate, as counted by [`Validators`].
+	pub fn api_validator_count() -> u32 {
+		Validators::<T>::count()
+	}
+
+	/// Returns the number of stashes that intend to nominate, as counted by [`Nominators`].
+	pub fn api_nominator_count() -> u32 {
+		Nominators::<T>::count()
+	}
+
+	/// Returns the slashes of `stash` as `(era, amount)` pairs, ordered by era.
+	///
+	/// The amount of an era is the sum of the slashes recorded for `stash` in that era as a
+	/// validator and as a nominator. Slashing metadata is only kept for the eras in [`BondedEras`],
+	/// so older slashes are not part of the history.
+	pub fn api_slash_history(stash: T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+		BondedEras::<T>::get()
+			.into_iter()
+			.filter_map(|(era, _)| {
+				let validator_slash = ValidatorSlashInEra::<T>::get(era, &stash).map(|(_, v)| v);
+				let nominator_slash = NominatorSlashInEra::<T>::get(era, &stash);
+				match (validator_slash, nominator_slash) {
+					(None, None) => None,
+					(v, n) =>
+						Some((era, v.unwrap_or_default().saturating_add(n.unwrap_or_default()))),
+				}
+			})
+			.collect()
+	}
+
+	/// Returns the validators that earned reward points in `era` with their points, sorted by
+	/// points in descending order.
+	///
+	/// Empty for eras outside of the history depth. This sorts all the rewarded validators of
+	/// `era` and is therefore only meant to be used off-chain.
+	pub fn api_reward_points_leaderboard(era: EraIndex) -> Vec<(T::AccountId, RewardPoint)> {
+		if !Self::era_within_history(era) {
+			return Vec::new()
+		}
+
+		let mut leaderboard: Vec<_> =
+			ErasRewardPoints::<T>::get(era).individual.into_iter().collect();
+		leaderboard.sort_by(|(_, a), (_, b)| b.cmp(a));
+		leaderboard
+	}
+
+	/// Returns the minimum active bond needed to take `role`, i.e. [`MinValidatorBond`] or
+	/// [`MinNominatorBond`].
+	pub fn api_minimum_bond(role: StakerRole) -> BalanceOf<T> {
+		match role {
+			StakerRole::Validator => MinValidatorBond::<T>::get(),
+			StakerRole::Nominator => MinNominatorBond::<T>::get(),
+		}
+	}
+
+	/// Returns the integrity state of the ledger bonded by `stash`, or `None` if `stash` is not
+	/// bonded.
+	pub fn api_ledger_integrity(stash: T::AccountId) -> Option<LedgerIntegrityState> {
+		Self::inspect_bond_state(&stash).ok()
+	}
+
+	/// Returns the change of the exposure of `validator` from `era_a` to `era_b`, according to
+	/// [`ErasStakersOverview`].
+	///
+	/// Returns `None` if `validator` has no exposure in either era.
+	pub fn api_exposure_delta(
+		era_a: EraIndex,
+		era_b: EraIndex,
+		validator: T::AccountId,
+	) -> Option<ExposureDelta<BalanceOf<T>>> {
+		let a = ErasStakersOverview::<T>::get(era_a, &validator)?;
+		let b = ErasStakersOverview::<T>::get(era_b, &validator)?;
+
+		Some(ExposureDelta {
+			total_increase: b.total.saturating_sub(a.total),
+			total_decrease: a.total.saturating_sub(b.total),
+			own_increase: b.own.saturating_sub(a.own),
+			own_decrease: a.own.saturating_sub(b.own),
+			nominator_count_change: (b.nominator_count as i32)
+				.saturating_sub(a.nominator_count as i32),
+		})
+	}
+
+	/// Returns an estimate of the annual yield of staking behind `validator`, after commission.
+	///
+	/// The reward shared among the exposure of `validator` in the last completed era is
+	/// annualized with the expected era duration, i.e. [`Self::sessions_per_era`] times the
+	/// average session length in blocks times [`Config::ExpectedBlockTime`]. This is only an
+	/// estimate: rewards vary from era to era and are not compounded.
+	///
+	/// Returns `None` if the reward or the exposure of `validator` in the last completed era is
+	/// not known, or if the era duration cannot be estimated.
+	pub fn api_apy_estimate(validator: T::AccountId) -> Option<Perbill> {
+		const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+
+		let era = Self::active_era()?.index.checked_sub(1)?;
+		let total = ErasStakersOverview::<T>::get(era, &validator)?.total;
+		let (leftover, _) = Self::validator_era_payout_split(era, &validator)?;
+
+		let era_duration = (Self::sessions_per_era() as u64)
+			.saturating_mul(T::NextNewSession::average_session_length().saturated_into::<u64>())
+			.saturating_mul(T::ExpectedBlockTime::get());
+		if era_duration.is_zero() || total.is_zero() {
+			return None
+		}
+
+		let annual_reward = leftover.saturating_mul(MILLISECONDS_PER_YEAR.saturated_into()) /
+			era_duration.saturated_into::<BalanceOf<T>>();
+		Some(Perbill::from_rational(annual_reward, total))
+	}
+
+	/// Returns the stashes of all the bonded stakers that are chilled, i.e. neither validating
+	/// nor nominating.
+	///
+	/// This iterates over the whole of [`Bonded`] and is therefore only meant to be used
+	/// off-chain.
+	pub fn api_chilled_stakers() -> Vec<T::AccountId> {
+		Bonded::<T>::iter_keys()
+			.filter(|stash| {
+				!Validators::<T>::contains_key(stash) && !Nominators::<T>::contains_key(stash)
+			})
+			.collect()
+	}
+
+	/// Returns the rewards that nominator `who` could still claim in each era within history
+	/// depth, oldest first, computed the same way as `do_payout_stakers_by_page`.
+	///
+	/// Only the unclaimed pages of exposure `who` is part of are taken into account, and eras
+	/// without any claimable reward are omitted. This iterates over all the exposure pages of
+	/// every era within history depth and is therefore only meant to be used off-chain.
+	pub fn api_pending_rewards_for(who: T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+		let Some(current_era) = CurrentEra::<T>::get() else { return Vec::new() };
+		(current_era.saturating_sub(T::HistoryDepth::get())..=current_era)
+			.filter_map(|era| {
+				let reward = ErasStakersPaged::<T>::iter_prefix((era,))
+					.filter_map(|((validator, page), exposure_page)| {
+						let value = exposure_page.others.iter().find(|e| e.who == who)?.value;
+						let ledger = Self::ledger(Stash(validator.clone())).ok()?;
+						if EraInfo::<T>::is_rewards_claimed_with_legacy_fallback(
+							era, &ledger, &validator, page,
+						) {
+							return None
+						}
+						let total = ErasStakersOverview::<T>::get(era, &validator)?.total;
+						let (leftover, _) = Self::validator_era_payout_split(era, &validator)?;
+						Some(Self::exposure_payout(value, total, leftover))
+					})
+					.fold(BalanceOf::<T>::zero(), |acc, reward| acc.saturating_add(reward));
+				(!reward.is_zero()).then_some((era, reward))
+			})
+			.collect()
+	}
+
+	/// Returns all the nominators backing `validator` in `era` along with the value of their
+	/// backing, across all the pages of its exposure.
+	///
+	/// Falls back to the legacy non-paged exposure if `era` has no paged exposure. The result can
+	/// be as large as the total number of nominators, hence this is only meant to be used
+	/// off-chain.
+	pub fn api_validator_backers(
+		era: EraIndex,
+		validator: T::AccountId,
+	) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		EraInfo::<T>::get_full_exposure(era, &validator)
+			.others
+			.into_iter()
+			.map(|backer| (backer.who, backer.value))
+			.collect()
+	}
+
+	/// Returns the stashes of all the validator candidates, i.e. every staker that has declared
+	/// the intention to validate, whether or not it has been elected.
+	///
+	/// This iterates over the whole of [`Validators`] and is therefore only meant to be used
+	/// off-chain.
+	pub fn api_election_candidates() -> Vec<T::AccountId> {
+		Validators::<T>::iter_keys().collect()
+	}
+
+	/// Returns the full exposure of `validator` in `era`, merging all of its exposure pages.
+	///
+	/// Falls back to the legacy non-paged exposure if `era` has no paged exposure, and returns
+	/// `None` if `validator` has no exposure at all in `era`.
+	pub fn api_full_exposure(
+		era: EraIndex,
+		validator: T::AccountId,
+	) -> Option<Exposure<T::AccountId, BalanceOf<T>>> {
+		let exposed = ErasStakersOverview::<T>::contains_key(era, &validator) ||
+			ErasStakers::<T>::contains_key(era, &validator);
+		exposed.then(|| EraInfo::<T>::get_full_exposure(era, &validator))
+	}
+
+	/// Returns the reward points of each of `validators` in `era`, in the same order.
+	///
+	/// Validators without any points in `era`, including the ones that were not elected, get 0.
+	pub fn api_era_points_for(
+		era: EraIndex,
+		validators: Vec<T::AccountId>,
+	) -> Vec<(T::AccountId, RewardPoint)> {
+		let points = ErasRewardPoints::<T>::get(era).individual;
+		validators
+			.into_iter()
+			.map(|validator| {
+				let validator_points = points.get(&validator).copied().unwrap_or_default();
+				(validator, validator_points)
+			})
+			.collect()
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1234,7 +2330,7 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		// Number of session in the current era or the maximum session per era if reached.
 		let era_progress = current_session
 			.saturating_sub(current_era_start_session_index)
-			.min(T::SessionsPerEra::get());
+			.min(Self::sessions_per_era());
 
 		let until_this_session_end = T::NextNewSession::estimate_next_new_session(now)
 			.0
@@ -1246,8 +2342,8 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		let sessions_left: BlockNumberFor<T> = match ForceEra::<T>::get() {
 			Forcing::ForceNone => Bounded::max_value(),
 			Forcing::ForceNew | Forcing::ForceAlways => Zero::zero(),
-			Forcing::NotForcing if era_progress >= T::SessionsPerEra::get() => Zero::zero(),
-			Forcing::NotForcing => T::SessionsPerEra::get()
+			Forcing::NotForcing if era_progress >= Self::sessions_per_era() => Zero::zero(),
+			Forcing::NotForcing => Self::sessions_per_era()
 				.saturating_sub(era_progress)
 				// One session is computed in this_session_end.
 				.saturating_sub(1)
@@ -1415,30 +2511,20 @@ where
 	}
 }
 
-/// This is intended to be used with `FilterHistoricalOffences`.
-impl<T: Config>
-	OnOffenceHandler<T::AccountId, pallet_session::historical::IdentificationTuple<T>, Weight>
-	for Pallet<T>
-where
-	T: pallet_session::Config<ValidatorId = <T as frame_system::Config>::AccountId>,
-	T: pallet_session::historical::Config<
-		FullIdentification = Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
-		FullIdentificationOf = ExposureOf<T>,
-	>,
-	T::SessionHandler: pallet_session::SessionHandler<<T as frame_system::Config>::AccountId>,
-	T::SessionManager: pallet_session::SessionManager<<T as frame_system::Config>::AccountId>,
-	T::ValidatorIdOf: Convert<
-		<T as frame_system::Config>::AccountId,
-		Option<<T as frame_system::Config>::AccountId>,
-	>,
-{
-	fn on_offence(
+impl<T: Config> Pallet<T> {
+	/// Handle offences like [`OnOffenceHandler::on_offence`] does, slashing only the own stake of
+	/// the offenders if `scope` is [`SlashScope::ValidatorOnly`].
+	///
+	/// This is meant for offences that the nominators of the offenders have no part in, e.g.
+	/// operational misconduct rather than equivocation.
+	pub fn on_offence_with_scope(
 		offenders: &[OffenceDetails<
 			T::AccountId,
-			pallet_session::historical::IdentificationTuple<T>,
+			(T::AccountId, Exposure<T::AccountId, BalanceOf<T>>),
 		>],
 		slash_fraction: &[Perbill],
 		slash_session: SessionIndex,
+		scope: SlashScope,
 	) -> Weight {
 		let reward_proportion = SlashRewardFraction::<T>::get();
 		let mut consumed_weight = Weight::from_parts(0, 0);
@@ -1495,19 +2581,28 @@ where
 				continue
 			}
 
+			// Exempt offences are still reported, but not slashed.
+			let slash_fraction = if T::OffenceExemption::is_exempt(stash, slash_era) {
+				Self::deposit_event(Event::<T>::SlashExempted { validator: stash.clone() });
+				Perbill::zero()
+			} else {
+				*slash_fraction
+			};
+
 			let unapplied = slashing::compute_slash::<T>(slashing::SlashParams {
 				stash,
-				slash: *slash_fraction,
+				slash: slash_fraction,
 				exposure,
 				slash_era,
 				window_start,
 				now: active_era,
 				reward_proportion,
+				scope,
 			});
 
 			Self::deposit_event(Event::<T>::SlashReported {
 				validator: stash.clone(),
-				fraction: *slash_fraction,
+				fraction: slash_fraction,
 				slash_era,
 			});
 
@@ -1521,9 +2616,11 @@ where
 					add_db_reads_writes(rw, rw);
 				}
 				unapplied.reporters = details.reporters.clone();
-				if slash_defer_duration == 0 {
+				let below_threshold =
+					T::SlashDeferThreshold::get().map_or(false, |t| slash_fraction < t);
+				if slash_defer_duration == 0 || below_threshold {
 					// Apply right away.
-					slashing::apply_slash::<T>(unapplied, slash_era);
+					slashing::apply_slash::<T>(unapplied, slash_era, slash_era);
 					{
 						let slash_cost = (6, 5);
 						let reward_cost = (2, 2);
@@ -1557,6 +2654,35 @@ where
 	}
 }
 
+/// This is intended to be used with `FilterHistoricalOffences`.
+impl<T: Config>
+	OnOffenceHandler<T::AccountId, pallet_session::historical::IdentificationTuple<T>, Weight>
+	for Pallet<T>
+where
+	T: pallet_session::Config<ValidatorId = <T as frame_system::Config>::AccountId>,
+	T: pallet_session::historical::Config<
+		FullIdentification = Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
+		FullIdentificationOf = ExposureOf<T>,
+	>,
+	T::SessionHandler: pallet_session::SessionHandler<<T as frame_system::Config>::AccountId>,
+	T::SessionManager: pallet_session::SessionManager<<T as frame_system::Config>::AccountId>,
+	T::ValidatorIdOf: Convert<
+		<T as frame_system::Config>::AccountId,
+		Option<<T as frame_system::Config>::AccountId>,
+	>,
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<
+			T::AccountId,
+			pallet_session::historical::IdentificationTuple<T>,
+		>],
+		slash_fraction: &[Perbill],
+		slash_session: SessionIndex,
+	) -> Weight {
+		Self::on_offence_with_scope(offenders, slash_fraction, slash_session, SlashScope::Full)
+	}
+}
+
 impl<T: Config> ScoreProvider<T::AccountId> for Pallet<T> {
 	type Score = VoteWeight;
 
@@ -1788,12 +2914,11 @@ impl<T: Config> StakingInterface for Pallet<T> {
 			Error::<T>::RewardDestinationRestricted
 		);
 
-		// since controller is deprecated and this function is never used for old ledgers with
-		// distinct controllers, we can safely assume that stash is the controller.
-		Self::set_payee(
-			RawOrigin::Signed(stash.clone()).into(),
-			RewardDestination::Account(reward_acc.clone()),
-		)
+		// the pallets managing virtual stakers rely on the payee being updated immediately, so
+		// `T::PayeeChangeDelay` does not apply here.
+		let ledger = Self::ledger(Stash(stash.clone()))?;
+		Self::do_set_payee(ledger, RewardDestination::Account(reward_acc.clone()))
+			.map_err(Into::into)
 	}
 
 	fn chill(who: &Self::AccountId) -> DispatchResult {
@@ -1822,7 +2947,12 @@ impl<T: Config> StakingInterface for Pallet<T> {
 			RawOrigin::Signed(who.clone()).into(),
 			value,
 			RewardDestination::Account(payee.clone()),
-		)
+		)?;
+
+		// the calling pallet manages this stash, it must not be reaped from under it.
+		ReapableStashes::<T>::remove(who);
+		IdleSince::<T>::remove(who);
+		Ok(())
 	}
 
 	fn nominate(who: &Self::AccountId, targets: Vec<Self::AccountId>) -> DispatchResult {
@@ -1856,6 +2986,11 @@ impl<T: Config> StakingInterface for Pallet<T> {
 			validator == *who || exposure_page.others.iter().any(|i| i.who == *who)
 		})
 	}
+
+	fn total_stake_in_era(era: EraIndex) -> Option<BalanceOf<T>> {
+		ErasTotalStake::<T>::try_get(era).ok()
+	}
+
 	fn status(
 		who: &Self::AccountId,
 	) -> Result<sp_staking::StakerStatus<Self::AccountId>, DispatchError> {
@@ -1918,9 +3053,16 @@ impl<T: Config> StakingInterface for Pallet<T> {
 }
 
 impl<T: Config> sp_staking::StakingUnchecked for Pallet<T> {
-	fn migrate_to_virtual_staker(who: &Self::AccountId) {
+	/// Migrates `who` to a virtual staker.
+	///
+	/// The current payee of `who` must not be the same as `who`.
+	fn migrate_to_virtual_staker(who: &Self::AccountId) -> DispatchResult {
+		let payee = Self::payee(StakingAccount::Stash(who.clone())).ok_or(Error::<T>::NotStash)?;
+		ensure!(!Self::is_self_payee(who, &payee), Error::<T>::RewardDestinationRestricted);
+
 		T::Currency::remove_lock(crate::STAKING_ID, who);
 		VirtualStakers::<T>::insert(who, ());
+		Ok(())
 	}
 
 	/// Virtually bonds `keyless_who` to `payee` with `value`.
@@ -1944,6 +3086,7 @@ impl<T: Config> sp_staking::StakingUnchecked for Pallet<T> {
 		// mark who as a virtual staker.
 		VirtualStakers::<T>::insert(keyless_who, ());
 
+		Self::deposit_event(Event::<T>::StakerOnboarded { stash: keyless_who.clone() });
 		Self::deposit_event(Event::<T>::Bonded { stash: keyless_who.clone(), amount: value });
 		let ledger = StakingLedger::<T>::new(keyless_who.clone(), value);
 
@@ -1952,6 +3095,14 @@ impl<T: Config> sp_staking::StakingUnchecked for Pallet<T> {
 		Ok(())
 	}
 
+	/// Virtually bonds `extra` on top of the existing bond of `who`.
+	///
+	/// `who` must already be a virtual staker.
+	fn virtual_bond_extra(who: &Self::AccountId, extra: Self::Balance) -> DispatchResult {
+		ensure!(Self::is_virtual_staker(who), Error::<T>::NotVirtualStaker);
+		Self::do_bond_extra(who, extra)
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn migrate_to_direct_staker(who: &Self::AccountId) {
 		assert!(VirtualStakers::<T>::contains_key(who));
@@ -2066,6 +3217,8 @@ impl<T: Config> Pallet<T> {
 	/// the system (validator is both voter and target).
 	/// * Number of targets in `TargetList` matches the number of validators in the system.
 	/// * Current validator count is bounded by the election provider's max winners.
+	/// * `BondedCount` matches the number of ledgers.
+	/// * `TotalUnlocking` matches the unlocking balance of all ledgers.
 	fn check_count() -> Result<(), TryRuntimeError> {
 		ensure!(
 			<T as Config>::VoterList::count() ==
@@ -2081,6 +3234,17 @@ impl<T: Config> Pallet<T> {
 				<T::ElectionProvider as frame_election_provider_support::ElectionProviderBase>::MaxWinners::get(),
 			Error::<T>::TooManyValidators
 		);
+		ensure!(
+			BondedCount::<T>::get() as usize == Ledger::<T>::iter().count(),
+			"wrong bonded count"
+		);
+		ensure!(
+			TotalUnlocking::<T>::get() ==
+				Ledger::<T>::iter().fold(Zero::zero(), |acc: BalanceOf<T>, (_, ledger)| {
+					acc.saturating_add(ledger.unlocking_balance())
+				}),
+			"wrong total unlocking"
+		);
 		Ok(())
 	}
 
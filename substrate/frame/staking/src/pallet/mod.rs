@@ -24,15 +24,16 @@ use frame_election_provider_support::{
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
-		Currency, Defensive, DefensiveSaturating, EnsureOrigin, EstimateNextNewSession, Get,
-		InspectLockableCurrency, LockableCurrency, OnUnbalanced, UnixTime, WithdrawReasons,
+		Currency, Defensive, DefensiveSaturating, EnsureOrigin, EstimateNextNewSession,
+		ExistenceRequirement, Get, InspectLockableCurrency, LockableCurrency, OnUnbalanced,
+		UnixTime, WithdrawReasons,
 	},
 	weights::Weight,
 	BoundedVec,
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 use sp_runtime::{
-	traits::{SaturatedConversion, StaticLookup, Zero},
+	traits::{SaturatedConversion, Saturating, StaticLookup, Zero},
 	ArithmeticError, Perbill, Percent,
 };
 
@@ -48,11 +49,12 @@ mod impls;
 pub use impls::*;
 
 use crate::{
-	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, DisablingStrategy,
-	EraPayout, EraRewardPoints, Exposure, ExposurePage, Forcing, LedgerIntegrityState,
-	MaxNominationsOf, NegativeImbalanceOf, Nominations, NominationsQuota, PositiveImbalanceOf,
-	RewardDestination, SessionInterface, StakingLedger, UnappliedSlash, UnlockChunk,
-	ValidatorPrefs,
+	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, ChillRole,
+	CommissionChangeRate, CommissionUpdate, DisablingStrategy, EraPayout, EraRewardPoints,
+	Exposure, ExposurePage, Forcing, FundsSource, LedgerIntegrityState, MaxNominationsOf,
+	MaxWinnersOf, NegativeImbalanceOf, Nominations, NominationsQuota, OffenceExemptionProvider,
+	OnEraEnd, OnEraStart, PoolRewardInterface, PositiveImbalanceOf, RewardDestination,
+	SessionInterface, StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
 };
 
 // The speculative number of spans are used as an input of the weight annotation of
@@ -60,6 +62,10 @@ use crate::{
 // account which is not provided as an input. The value set should be conservative but sensible.
 pub(crate) const SPECULATIVE_NUM_SPANS: u32 = 32;
 
+// The bounds to which [`Call::set_sessions_per_era`] clamps the number of sessions per era.
+pub(crate) const MIN_SESSIONS_PER_ERA: SessionIndex = 1;
+pub(crate) const MAX_SESSIONS_PER_ERA: SessionIndex = 256;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_election_provider_support::ElectionDataProvider;
@@ -69,7 +75,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(15);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(17);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -199,12 +205,50 @@ pub mod pallet {
 		#[pallet::constant]
 		type SlashDeferDuration: Get<EraIndex>;
 
+		/// The slash fraction from which offences are deferred by `SlashDeferDuration`, if any.
+		///
+		/// Offences with a smaller slash fraction are applied immediately. If `None`, all slashes
+		/// are deferred.
+		#[pallet::constant]
+		type SlashDeferThreshold: Get<Option<Perbill>>;
+
 		/// The origin which can manage less critical staking parameters that does not require root.
 		///
 		/// Supported actions: (1) cancel deferred slash, (2) set minimum commission.
 		#[pallet::no_default]
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which can (re-)set the reward destination of a ledger via
+		/// [`Call::set_payee`].
+		///
+		/// On success, it must return the controller account of the ledger to update. This allows
+		/// e.g. a recovery or proxy pallet to be authorized to change the payee on behalf of a
+		/// controller. Use [`crate::EnsureSignedController`] to only allow the controller itself.
+		#[pallet::no_default_bounds]
+		type PayeeControlOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The number of eras after which a payee change requested through [`Call::set_payee`]
+		/// takes effect, or `None` for payee changes to take effect immediately.
+		///
+		/// Delaying payee changes leaves stakers time to cancel a change made with a compromised
+		/// key through [`Call::cancel_payee_change`].
+		#[pallet::constant]
+		type PayeeChangeDelay: Get<Option<EraIndex>>;
+
+		/// The maximum number of [`PendingPayee`] entries inspected per block when applying
+		/// delayed payee changes.
+		#[pallet::constant]
+		type PendingPayeeScanLimit: Get<u32>;
+
+		/// The origin which can bond funds of an escrow account into a ledger via
+		/// [`Call::bond_extra_from_escrow`].
+		///
+		/// On success, it must return the controller account of the ledger to bond into. The escrow
+		/// must have approved the stash of that ledger through [`Call::set_escrow_approval`]. Use
+		/// [`crate::EnsureSignedController`] to only allow the controller itself.
+		#[pallet::no_default_bounds]
+		type EscrowBondOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
 		/// Interface for interacting with a session pallet.
 		type SessionInterface: SessionInterface<Self::AccountId>;
 
@@ -282,6 +326,85 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUnlockingChunks: Get<u32>;
 
+		/// Whether an `unbond` that would leave the active bond below the minimum bond of the
+		/// staker's role should chill the staker first, instead of failing with
+		/// [`Error::InsufficientBond`].
+		#[pallet::constant]
+		type AutoChillOnUnbond: Get<bool>;
+
+		/// Whether [`Call::chill_other`] requires [`MaxNominatorsCount`] and
+		/// [`MaxValidatorsCount`] to be set and close to being reached, as defined by
+		/// [`ChillThreshold`].
+		///
+		/// If `false`, any staker below the minimum bond of its role can be chilled by anyone as
+		/// soon as a [`ChillThreshold`] is set, regardless of the number of stakers.
+		#[pallet::constant]
+		type ChillOtherRequiresCountLimit: Get<bool>;
+
+		/// The maximum number of stakers that can be chilled in one [`Call::chill_other_batch`].
+		#[pallet::constant]
+		type MaxChillOtherBatch: Get<u32>;
+
+		/// The maximum number of stashes that can be unstaked in one [`Call::force_unstake_batch`].
+		#[pallet::constant]
+		type MaxForceUnstakeBatch: Get<u32>;
+
+		/// The maximum number of pages that can be paid out in one [`Call::payout_multiple`].
+		#[pallet::constant]
+		type MaxPayoutBatch: Get<u32>;
+
+		/// The maximum `total` stake a single [`StakingLedger`] can have, if any.
+		///
+		/// Explicit bonds that would exceed it are rejected, whereas rewards that would be
+		/// compounded above it are paid out to the stash as free balance instead.
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type MaxLedgerTotal: Get<Option<BalanceOf<Self>>>;
+
+		/// An amount added on top of the existential deposit to obtain the minimum value of a new
+		/// bond.
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type BondEdBuffer: Get<BalanceOf<Self>>;
+
+		/// Unbonds of a value strictly below this amount still create an unlocking chunk but do
+		/// not emit [`Event::Unbonded`].
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type MinEventedUnbond: Get<BalanceOf<Self>>;
+
+		/// The number of eras a stash must have been idle, i.e. neither validating nor nominating,
+		/// before anyone can reap it through [`Call::reap_idle`].
+		///
+		/// `None` disables reaping idle stashes.
+		#[pallet::constant]
+		type IdleReapEras: Get<Option<EraIndex>>;
+
+		/// The expected duration of a block in milliseconds.
+		///
+		/// Only used to estimate the annual yield of validators, see
+		/// [`Pallet::api_apy_estimate`].
+		#[pallet::constant]
+		type ExpectedBlockTime: Get<u64>;
+
+		/// The maximum number of [`NominationExpiry`] entries inspected per block when chilling
+		/// nominators whose nominations have expired.
+		#[pallet::constant]
+		type NominationExpiryScanLimit: Get<u32>;
+
+		/// Whether the rewards of an era are automatically paid out to all its validators and
+		/// their nominators once the next era starts, without anyone calling
+		/// [`Call::payout_stakers`].
+		///
+		/// Only meant for chains with a small validator set. Manual payouts keep working.
+		#[pallet::constant]
+		type AutoPayout: Get<bool>;
+
+		/// The maximum number of payouts, each paying a single exposure page, attempted per block
+		/// when [`Config::AutoPayout`] is enabled.
+		#[pallet::constant]
+		type MaxAutoPayoutsPerBlock: Get<u32>;
+
 		/// The maximum amount of controller accounts that can be deprecated in one call.
 		type MaxControllersInDeprecationBatch: Get<u32>;
 
@@ -296,6 +419,25 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type DisablingStrategy: DisablingStrategy<Self>;
 
+		/// Decides which offences are exempt from slashing, e.g. because the offender is known to
+		/// have been online.
+		///
+		/// Exempt offences are reported with a zero slash fraction.
+		#[pallet::no_default_bounds]
+		type OffenceExemption: OffenceExemptionProvider<Self::AccountId>;
+
+		/// The nomination pools that rewards can be routed into with [`RewardDestination::Pool`].
+		///
+		/// `()` rejects [`RewardDestination::Pool`] altogether.
+		#[pallet::no_default_bounds]
+		type PoolInterface: PoolRewardInterface<Self::AccountId, BalanceOf<Self>>;
+
+		/// Hook invoked with the index of each era that becomes active.
+		type OnEraStart: OnEraStart;
+
+		/// Hook invoked with the index of each active era that ends.
+		type OnEraEnd: OnEraEnd;
+
 		/// Some parameters of the benchmarking.
 		#[cfg(feature = "std")]
 		type BenchmarkingConfig: BenchmarkingConfig;
@@ -311,7 +453,10 @@ pub mod pallet {
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
-		use frame_support::{derive_impl, parameter_types, traits::ConstU32};
+		use frame_support::{
+			derive_impl, parameter_types,
+			traits::{ConstBool, ConstU32},
+		};
 		pub struct TestDefaultConfig;
 
 		#[derive_impl(frame_system::config_preludes::TestDefaultConfig, no_aggregated_types)]
@@ -336,13 +481,35 @@ pub mod pallet {
 			type SessionsPerEra = SessionsPerEra;
 			type BondingDuration = BondingDuration;
 			type SlashDeferDuration = ();
+			type SlashDeferThreshold = ();
+			type PayeeControlOrigin = crate::EnsureSignedController;
+			type PayeeChangeDelay = ();
+			type PendingPayeeScanLimit = ConstU32<64>;
+			type EscrowBondOrigin = crate::EnsureSignedController;
 			type SessionInterface = ();
 			type NextNewSession = ();
 			type MaxExposurePageSize = ConstU32<64>;
 			type MaxUnlockingChunks = ConstU32<32>;
+			type AutoChillOnUnbond = ConstBool<false>;
+			type ChillOtherRequiresCountLimit = ConstBool<true>;
+			type MaxChillOtherBatch = ConstU32<64>;
+			type MaxForceUnstakeBatch = ConstU32<64>;
+			type MaxPayoutBatch = ConstU32<16>;
+			type MaxLedgerTotal = ();
+			type BondEdBuffer = ();
+			type MinEventedUnbond = ();
+			type IdleReapEras = ();
+			type ExpectedBlockTime = ();
+			type NominationExpiryScanLimit = ConstU32<64>;
+			type AutoPayout = ConstBool<false>;
+			type MaxAutoPayoutsPerBlock = ConstU32<16>;
 			type MaxControllersInDeprecationBatch = ConstU32<100>;
 			type EventListeners = ();
 			type DisablingStrategy = crate::UpToLimitDisablingStrategy;
+			type OffenceExemption = ();
+			type PoolInterface = ();
+			type OnEraStart = ();
+			type OnEraEnd = ();
 			#[cfg(feature = "std")]
 			type BenchmarkingConfig = crate::TestBenchmarkingConfig;
 			type WeightInfo = ();
@@ -452,6 +619,120 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type VirtualStakers<T: Config> = CountedStorageMap<_, Twox64Concat, T::AccountId, ()>;
 
+	/// The number of bonded stashes, including the idle ones.
+	///
+	/// This should always be equal to the number of entries in [`Ledger`].
+	#[pallet::storage]
+	pub type BondedCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The total balance in the unlocking chunks of all the ledgers, i.e. pending withdrawal.
+	///
+	/// This should always be equal to the sum of the unlocking balance of all entries in
+	/// [`Ledger`].
+	#[pallet::storage]
+	pub type TotalUnlocking<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The era from which the nominations of a stash, set through [`Call::nominate_until`], are
+	/// considered expired and the stash is chilled.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type NominationExpiry<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EraIndex>;
+
+	/// Cursor of the ongoing scan of [`NominationExpiry`], started at the beginning of each era.
+	///
+	/// `Some(None)` means the scan starts from the first entry, and `Some(Some(stash))` means it
+	/// resumes after `stash`. No scan is ongoing if this is `None`.
+	#[pallet::storage]
+	pub(crate) type NominationExpiryCursor<T: Config> = StorageValue<_, Option<T::AccountId>>;
+
+	/// Payee changes scheduled through [`Call::set_payee`], along with the era from which they
+	/// take effect. See [`Config::PayeeChangeDelay`].
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type PendingPayee<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (EraIndex, RewardDestination<T::AccountId>)>;
+
+	/// Cursor of the ongoing scan of [`PendingPayee`], started at the beginning of each era.
+	///
+	/// `Some(None)` means the scan starts from the first entry, and `Some(Some(stash))` means it
+	/// resumes after `stash`. No scan is ongoing if this is `None`.
+	#[pallet::storage]
+	pub(crate) type PendingPayeeCursor<T: Config> = StorageValue<_, Option<T::AccountId>>;
+
+	/// The stashes that an escrow account allows to bond its funds through
+	/// [`Call::bond_extra_from_escrow`], keyed by escrow and then by stash.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type EscrowApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, ()>;
+
+	/// The active era from which a stash has been idle, i.e. neither validating nor nominating.
+	///
+	/// Only tracked while [`Config::IdleReapEras`] is set.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type IdleSince<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EraIndex>;
+
+	/// The stashes that bonded themselves through [`Call::bond`], and that can hence be reaped by
+	/// [`Call::reap_idle`] once idle.
+	///
+	/// Stashes bonded by other pallets through [`StakingInterface`] are never part of it, since
+	/// reaping them would bring their ledger out of sync with the accounting of these pallets.
+	/// Neither are the stashes bonded before this item was introduced.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ReapableStashes<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, ()>;
+
+	/// The limit on how fast validators can raise their commission, if any.
+	///
+	/// Lowering the commission is never limited.
+	#[pallet::storage]
+	pub type CommissionChangeRateLimit<T> = StorageValue<_, CommissionChangeRate, OptionQuery>;
+
+	/// The last commission of a validator and the active era in which it last raised it.
+	///
+	/// This is kept when the validator chills, so that validating again is limited by
+	/// [`CommissionChangeRateLimit`] as well. It is only removed once the stash is killed.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type LastCommissionUpdate<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, CommissionUpdate>;
+
+	/// The minimum stake a nominator must back a validator with to be part of its exposure.
+	///
+	/// Smaller backings are dropped from the exposure of the validator when the election result
+	/// is stored, they do not count towards its total. The ledgers and nominations of the dropped
+	/// nominators are left untouched.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ValidatorNominationFilter<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>>;
+
+	/// Cursor of the ongoing automatic payout of an era, see [`Config::AutoPayout`].
+	///
+	/// `Some((era, None))` means the payout of `era` starts from its first validator, and
+	/// `Some((era, Some(stash)))` means it resumes after `stash`. No payout is ongoing if this is
+	/// `None`.
+	#[pallet::storage]
+	pub(crate) type AutoPayoutCursor<T: Config> = StorageValue<_, (EraIndex, Option<T::AccountId>)>;
+
+	/// Accounts that are restricted from participating in staking, managed through
+	/// [`Call::restrict_account`] and [`Call::unrestrict_account`].
+	///
+	/// Restricted accounts cannot bond or rebond any funds, but can still chill, unbond and
+	/// withdraw their existing bond.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type RestrictedAccounts<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, ()>;
+
 	/// The maximum nominator count before we stop allowing new validators to join.
 	///
 	/// When this value is not set, no limits are enforced.
@@ -482,6 +763,12 @@ pub mod pallet {
 	#[pallet::getter(fn eras_start_session_index)]
 	pub type ErasStartSessionIndex<T> = StorageMap<_, Twox64Concat, EraIndex, SessionIndex>;
 
+	/// The block number at which the era started being active, for the last
+	/// [`Config::HistoryDepth`] eras.
+	#[pallet::storage]
+	#[pallet::getter(fn era_start_block)]
+	pub type ErasStartBlock<T: Config> = StorageMap<_, Twox64Concat, EraIndex, BlockNumberFor<T>>;
+
 	/// Exposure of validator at era.
 	///
 	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
@@ -637,6 +924,17 @@ pub mod pallet {
 	#[pallet::getter(fn force_era)]
 	pub type ForceEra<T> = StorageValue<_, Forcing, ValueQuery>;
 
+	/// Overrides [`Config::SessionsPerEra`] for the current era, if set.
+	#[pallet::storage]
+	pub type SessionsPerEraOverride<T> = StorageValue<_, SessionIndex>;
+
+	/// A change of [`SessionsPerEraOverride`] requested through [`Call::set_sessions_per_era`],
+	/// applied once the next era is planned.
+	///
+	/// `Some(None)` means the override is removed.
+	#[pallet::storage]
+	pub(crate) type PendingSessionsPerEraOverride<T> = StorageValue<_, Option<SessionIndex>>;
+
 	/// Maximum staked rewards, i.e. the percentage of the era inflation that
 	/// is used for stake rewards.
 	/// See [Era payout](./index.html#era-payout).
@@ -817,31 +1115,52 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// The era payout has been set; the first balance is the validator-payout; the second is
-		/// the remainder from the maximum amount of reward.
-		EraPaid { era_index: EraIndex, validator_payout: BalanceOf<T>, remainder: BalanceOf<T> },
+		/// the remainder from the maximum amount of reward; the third is the total amount staked
+		/// in the era.
+		EraPaid {
+			era_index: EraIndex,
+			validator_payout: BalanceOf<T>,
+			remainder: BalanceOf<T>,
+			total_staked: BalanceOf<T>,
+		},
 		/// The nominator has been rewarded by this amount to this destination.
 		Rewarded {
 			stash: T::AccountId,
 			dest: RewardDestination<T::AccountId>,
 			amount: BalanceOf<T>,
 		},
-		/// A staker (validator or nominator) has been slashed by the given amount.
-		Slashed { staker: T::AccountId, amount: BalanceOf<T> },
+		/// A staker (validator or nominator) has been slashed by the given amount, for an offence
+		/// committed in `slash_era`, which falls in the staker's slashing span `span_index`.
+		Slashed {
+			staker: T::AccountId,
+			amount: BalanceOf<T>,
+			slash_era: EraIndex,
+			span_index: slashing::SpanIndex,
+		},
 		/// A slash for the given validator, for the given percentage of their stake, at the given
 		/// era as been reported.
 		SlashReported { validator: T::AccountId, fraction: Perbill, slash_era: EraIndex },
 		/// An old slashing report from a prior era was discarded because it could
 		/// not be processed.
 		OldSlashingReportDiscarded { session_index: SessionIndex },
-		/// A new set of stakers was elected.
-		StakersElected,
+		/// A new set of `count` validators was elected, backed by `total_stake` in total.
+		StakersElected { count: u32, total_stake: BalanceOf<T> },
 		/// An account has bonded this amount. \[stash, amount\]
 		///
 		/// NOTE: This event is only emitted when funds are bonded via a dispatchable. Notably,
 		/// it will not be emitted for staking rewards when they are added to stake.
 		Bonded { stash: T::AccountId, amount: BalanceOf<T> },
-		/// An account has unbonded this amount.
-		Unbonded { stash: T::AccountId, amount: BalanceOf<T> },
+		/// An account has bonded this extra amount on top of an existing bond, resulting in
+		/// `new_active` being actively bonded.
+		BondedExtra { stash: T::AccountId, amount: BalanceOf<T>, new_active: BalanceOf<T> },
+		/// An account has unbonded this amount, leaving `remaining_active` actively bonded. The
+		/// amount can be withdrawn from `withdraw_era` onwards.
+		Unbonded {
+			stash: T::AccountId,
+			amount: BalanceOf<T>,
+			remaining_active: BalanceOf<T>,
+			withdraw_era: EraIndex,
+		},
 		/// An account has called `withdraw_unbonded` and removed unbonding chunks worth `Balance`
 		/// from the unlocking queue.
 		Withdrawn { stash: T::AccountId, amount: BalanceOf<T> },
@@ -853,8 +1172,13 @@ pub mod pallet {
 		Chilled { stash: T::AccountId },
 		/// The stakers' rewards are getting paid.
 		PayoutStarted { era_index: EraIndex, validator_stash: T::AccountId },
-		/// A validator has set their preferences.
-		ValidatorPrefsSet { stash: T::AccountId, prefs: ValidatorPrefs },
+		/// A validator has set their preferences. `old_prefs` is `None` if the stash was not
+		/// validating before.
+		ValidatorPrefsSet {
+			stash: T::AccountId,
+			old_prefs: Option<ValidatorPrefs>,
+			new_prefs: ValidatorPrefs,
+		},
 		/// Voters size limit reached.
 		SnapshotVotersSizeExceeded { size: u32 },
 		/// Targets size limit reached.
@@ -863,6 +1187,70 @@ pub mod pallet {
 		ForceEra { mode: Forcing },
 		/// Report of a controller batch deprecation.
 		ControllerBatchDeprecated { failures: u32 },
+		/// An account has been restricted from bonding.
+		AccountRestricted { who: T::AccountId },
+		/// An account is no longer restricted from bonding.
+		AccountUnrestricted { who: T::AccountId },
+		/// An offence of the given validator has been exempted from slashing.
+		SlashExempted { validator: T::AccountId },
+		/// The number of sessions per era has been overridden, effective from the next era.
+		/// `None` means the override has been removed.
+		SessionsPerEraSet { value: Option<SessionIndex> },
+		/// A new era has started and its reward points are accumulated from now on, until the
+		/// next era starts.
+		EraRewardPointsReset { era: EraIndex },
+		/// A batch of stakers has been chilled through [`Call::chill_other_batch`].
+		ChilledBatch { count: u32 },
+		/// A fresh stash has been bonded for the first time. Always followed by
+		/// [`Event::Bonded`].
+		StakerOnboarded { stash: T::AccountId },
+		/// The active stake of a validator dropped below the lowest total stake elected in the
+		/// active era after unbonding, so it might not be elected in upcoming eras.
+		ValidatorStakeWarning {
+			stash: T::AccountId,
+			new_active: BalanceOf<T>,
+			min_to_stay_elected: BalanceOf<T>,
+		},
+		/// A payee change of a stash has been scheduled to take effect in `era`.
+		PayeeChangeScheduled {
+			stash: T::AccountId,
+			payee: RewardDestination<T::AccountId>,
+			era: EraIndex,
+		},
+		/// The payee change scheduled for a stash has been cancelled.
+		PayeeChangeCancelled { stash: T::AccountId },
+		/// The payee of a stash has been set.
+		PayeeSet { stash: T::AccountId, payee: RewardDestination<T::AccountId> },
+		/// The payout of `page` of the rewards of `validator` in `era` has been requested by
+		/// `caller`. Emitted after the corresponding [`Event::Rewarded`] events.
+		RewardClaimedBy { caller: T::AccountId, validator: T::AccountId, era: EraIndex, page: Page },
+		/// `stash` has chilled itself through [`Call::chill`], `role` is what it was before.
+		ChilledWithRole { stash: T::AccountId, role: ChillRole },
+		/// `escrow` has allowed or disallowed `stash` to bond its funds.
+		EscrowApprovalSet { escrow: T::AccountId, stash: T::AccountId, approved: bool },
+		/// The nominations of `nominator` have been dropped by [`Call::chill`], `had_targets` is
+		/// the number of validators it nominated.
+		NominationsCleared { nominator: T::AccountId, had_targets: u32 },
+		/// `stash` could not be unstaked by [`Call::force_unstake_batch`].
+		ForceUnstakeFailed { stash: T::AccountId, error: DispatchError },
+		/// A batch of stashes has been processed by [`Call::force_unstake_batch`].
+		ForceUnstakedBatch { unstaked: u32, failed: u32 },
+		/// `amount` has been withdrawn from the unlocked funds of `stash` and sent to `dest`
+		/// through [`Call::withdraw_unbonded_to`]. Always preceded by [`Event::Withdrawn`].
+		WithdrawnTo { stash: T::AccountId, amount: BalanceOf<T>, dest: T::AccountId },
+		/// The reward of `era` could not be minted, so the era is not paid out at all. Emitted
+		/// instead of [`Event::EraPaid`].
+		EraRewardMintFailed { era: EraIndex },
+		/// The commission of the validator `stash` has changed from `old` to `new`.
+		CommissionChanged { stash: T::AccountId, old: Perbill, new: Perbill },
+		/// The `stashes` passed to [`Call::chill_other_batch`] could not be chilled and have been
+		/// skipped.
+		ChilledBatchSkipped { stashes: Vec<T::AccountId> },
+		/// The minimum nominator stake accepted by the validator `stash` has been set to `min`.
+		MinNominatorStakeSet { stash: T::AccountId, min: Option<BalanceOf<T>> },
+		/// `amount` of the reward of `stash` was paid out as free balance instead of being staked,
+		/// since its ledger reached [`Config::MaxLedgerTotal`].
+		RewardNotCompounded { stash: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -934,13 +1322,46 @@ pub mod pallet {
 		NotEnoughFunds,
 		/// Operation not allowed for virtual stakers.
 		VirtualStakerNotAllowed,
+		/// The ledger would exceed the maximum total stake set by `T::MaxLedgerTotal`.
+		LedgerCapExceeded,
+		/// The expiry era of the nominations is not in the future.
+		InvalidNominationExpiry,
+		/// Operation only allowed for virtual stakers.
+		NotVirtualStaker,
+		/// Account is restricted from participating in staking.
+		Restricted,
+		/// The stash has no pending payee change.
+		NoPendingPayeeChange,
+		/// The escrow has not approved the stash to bond its funds.
+		EscrowNotApproved,
+		/// The minimum validator count is zero or greater than the validator count.
+		InvalidMinimumValidatorCount,
+		/// Reaping idle stashes is disabled, see [`Config::IdleReapEras`].
+		IdleReapDisabled,
+		/// The stash has not been idle for long enough to be reaped, or is still exposed.
+		NotIdleLongEnough,
+		/// Rewards of the stash cannot be routed into the given nomination pool.
+		PoolPayeeUnavailable,
+		/// The commission has already been raised too recently, see
+		/// [`CommissionChangeRateLimit`].
+		CommissionUpdateTooFrequent,
+		/// The commission cannot be raised by that much at once, see
+		/// [`CommissionChangeRateLimit`].
+		CommissionIncreaseTooLarge,
+		/// The stash is not a validator.
+		NotValidator,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
-			// just return the weight of the on_finalize.
-			T::DbWeight::get().reads(1)
+			// the weight of the on_finalize, plus the work of chilling expired nominators, of
+			// applying delayed payee changes and of paying out the previous era.
+			T::DbWeight::get()
+				.reads(1)
+				.saturating_add(Self::chill_expired_nominators())
+				.saturating_add(Self::apply_pending_payees())
+				.saturating_add(Self::auto_payout_stakers())
 		}
 
 		fn on_finalize(_n: BlockNumberFor<T>) {
@@ -1013,6 +1434,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let stash = ensure_signed(origin)?;
 
+			ensure!(!Self::is_restricted(&stash), Error::<T>::Restricted);
+
 			if StakingLedger::<T>::is_bonded(StakingAccount::Stash(stash.clone())) {
 				return Err(Error::<T>::AlreadyBonded.into())
 			}
@@ -1023,7 +1446,7 @@ pub mod pallet {
 			}
 
 			// Reject a bond which is considered to be _dust_.
-			if value < T::Currency::minimum_balance() {
+			if value < T::Currency::minimum_balance().saturating_add(T::BondEdBuffer::get()) {
 				return Err(Error::<T>::InsufficientBond.into())
 			}
 
@@ -1031,12 +1454,20 @@ pub mod pallet {
 
 			let stash_balance = T::Currency::free_balance(&stash);
 			let value = value.min(stash_balance);
+			ensure!(
+				T::MaxLedgerTotal::get().map_or(true, |cap| value <= cap),
+				Error::<T>::LedgerCapExceeded
+			);
+			Self::ensure_payee_available(&stash, &payee)?;
+			Self::deposit_event(Event::<T>::StakerOnboarded { stash: stash.clone() });
 			Self::deposit_event(Event::<T>::Bonded { stash: stash.clone(), amount: value });
 			let ledger = StakingLedger::<T>::new(stash.clone(), value);
 
 			// You're auto-bonded forever, here. We might improve this by only bonding when
 			// you actually validate/nominate and remove once you unbond __everything__.
 			ledger.bond(payee)?;
+			ReapableStashes::<T>::insert(&stash, ());
+			Self::note_idle(&stash);
 
 			Ok(())
 		}
@@ -1050,7 +1481,7 @@ pub mod pallet {
 		/// Unlike [`bond`](Self::bond) or [`unbond`](Self::unbond) this function does not impose
 		/// any limitation on the amount that can be added.
 		///
-		/// Emits `Bonded`.
+		/// Emits `BondedExtra`.
 		///
 		/// ## Complexity
 		/// - Independent of the arguments. Insignificant complexity.
@@ -1062,6 +1493,7 @@ pub mod pallet {
 			#[pallet::compact] max_additional: BalanceOf<T>,
 		) -> DispatchResult {
 			let stash = ensure_signed(origin)?;
+			ensure!(!Self::is_restricted(&stash), Error::<T>::Restricted);
 			Self::do_bond_extra(&stash, max_additional)
 		}
 
@@ -1079,14 +1511,21 @@ pub mod pallet {
 		/// [`Call::withdraw_unbonded`] is called to remove some of the chunks (if possible).
 		///
 		/// If a user encounters the `InsufficientBond` error when calling this extrinsic,
-		/// they should call `chill` first in order to free up their bonded funds.
+		/// they should call `chill` first in order to free up their bonded funds. If
+		/// `T::AutoChillOnUnbond` is set, the staker is instead chilled automatically.
 		///
-		/// Emits `Unbonded`.
+		/// Emits `Unbonded`, and `Chilled` if the staker was automatically chilled. Emits
+		/// `ValidatorStakeWarning` if the active stake of a validator drops below
+		/// [`Pallet::minimum_stake_to_elect`].
 		///
 		/// See also [`Call::withdraw_unbonded`].
 		#[pallet::call_index(2)]
 		#[pallet::weight(
-            T::WeightInfo::withdraw_unbonded_kill(SPECULATIVE_NUM_SPANS).saturating_add(T::WeightInfo::unbond()))
+            T::WeightInfo::withdraw_unbonded_kill(SPECULATIVE_NUM_SPANS)
+                .saturating_add(T::WeightInfo::unbond())
+                .saturating_add(T::WeightInfo::chill())
+                // `minimum_stake_to_elect` when unbonding from a validator.
+                .saturating_add(T::DbWeight::get().reads(1 + MaxWinnersOf::<T>::get() as u64)))
         ]
 		pub fn unbond(
 			origin: OriginFor<T>,
@@ -1113,6 +1552,7 @@ pub mod pallet {
 			let mut ledger = Self::ledger(Controller(controller))?;
 			let mut value = value.min(ledger.active);
 			let stash = ledger.stash.clone();
+			let mut maybe_warning_weight = None;
 
 			ensure!(
 				ledger.unlocking.len() < T::MaxUnlockingChunks::get() as usize,
@@ -1120,6 +1560,7 @@ pub mod pallet {
 			);
 
 			if !value.is_zero() {
+				let previous_active = ledger.active;
 				ledger.active -= value;
 
 				// Avoid there being a dust balance left in the staking system.
@@ -1137,8 +1578,12 @@ pub mod pallet {
 				};
 
 				// Make sure that the user maintains enough active bond for their role.
-				// If a user runs into this error, they should chill first.
-				ensure!(ledger.active >= min_active_bond, Error::<T>::InsufficientBond);
+				// If a user runs into this error, they should chill first, unless
+				// `T::AutoChillOnUnbond` is set, in which case they are chilled here.
+				if ledger.active < min_active_bond {
+					ensure!(T::AutoChillOnUnbond::get(), Error::<T>::InsufficientBond);
+					Self::chill_stash(&stash);
+				}
 
 				// Note: in case there is no current era it is fine to bond one era more.
 				let era = Self::current_era()
@@ -1155,6 +1600,7 @@ pub mod pallet {
 						.try_push(UnlockChunk { value, era })
 						.map_err(|_| Error::<T>::NoMoreChunks)?;
 				};
+				let remaining_active = ledger.active;
 				// NOTE: ledger must be updated prior to calling `Self::weight_of`.
 				ledger.update()?;
 
@@ -1163,14 +1609,39 @@ pub mod pallet {
 					let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash)).defensive();
 				}
 
-				Self::deposit_event(Event::<T>::Unbonded { stash, amount: value });
+				if value >= T::MinEventedUnbond::get() {
+					Self::deposit_event(Event::<T>::Unbonded {
+						stash: stash.clone(),
+						amount: value,
+						remaining_active,
+						withdraw_era: era,
+					});
+				}
+
+				// warn validators that are about to drop out of the elected set.
+				if Validators::<T>::contains_key(&stash) {
+					maybe_warning_weight =
+						Some(T::DbWeight::get().reads(1 + MaxWinnersOf::<T>::get() as u64));
+					if let Some(min_to_stay_elected) = Self::minimum_stake_to_elect() {
+						if remaining_active < min_to_stay_elected &&
+							previous_active >= min_to_stay_elected
+						{
+							Self::deposit_event(Event::<T>::ValidatorStakeWarning {
+								stash,
+								new_active: remaining_active,
+								min_to_stay_elected,
+							});
+						}
+					}
+				}
 			}
 
 			let actual_weight = if let Some(withdraw_weight) = maybe_withdraw_weight {
 				Some(T::WeightInfo::unbond().saturating_add(withdraw_weight))
 			} else {
 				Some(T::WeightInfo::unbond())
-			};
+			}
+			.map(|weight| weight.saturating_add(maybe_warning_weight.unwrap_or_default()));
 
 			Ok(actual_weight.into())
 		}
@@ -1215,8 +1686,11 @@ pub mod pallet {
 		/// Effects will be felt at the beginning of the next era.
 		///
 		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
+		///
+		/// Raising the commission of an existing validator is subject to
+		/// [`CommissionChangeRateLimit`], also after it was chilled.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::validate())]
+		#[pallet::weight(Pallet::<T>::validate_weight())]
 		pub fn validate(origin: OriginFor<T>, prefs: ValidatorPrefs) -> DispatchResult {
 			let controller = ensure_signed(origin)?;
 
@@ -1228,8 +1702,11 @@ pub mod pallet {
 			// ensure their commission is correct.
 			ensure!(prefs.commission >= MinCommission::<T>::get(), Error::<T>::CommissionTooLow);
 
+			let old_prefs =
+				Validators::<T>::contains_key(stash).then(|| Validators::<T>::get(stash));
+
 			// Only check limits if they are not already a validator.
-			if !Validators::<T>::contains_key(stash) {
+			if old_prefs.is_none() {
 				// If this error is reached, we need to adjust the `MinValidatorBond` and start
 				// calling `chill_other`. Until then, we explicitly block new validators to protect
 				// the runtime.
@@ -1241,9 +1718,41 @@ pub mod pallet {
 				}
 			}
 
+			let last_update = LastCommissionUpdate::<T>::get(stash);
+			let old_commission =
+				old_prefs.as_ref().map(|p| p.commission).or(last_update.map(|u| u.commission));
+			if let Some(old_commission) = old_commission {
+				if prefs.commission > old_commission {
+					Self::ensure_commission_increase_allowed(
+						stash,
+						old_commission,
+						prefs.commission,
+					)?;
+					let era = Self::active_era().map(|e| e.index).unwrap_or(0);
+					LastCommissionUpdate::<T>::insert(
+						stash,
+						CommissionUpdate { commission: prefs.commission, raised_in: Some(era) },
+					);
+				}
+			}
+
+			if let Some(ref old_prefs) = old_prefs {
+				if prefs.commission != old_prefs.commission {
+					Self::deposit_event(Event::<T>::CommissionChanged {
+						stash: stash.clone(),
+						old: old_prefs.commission,
+						new: prefs.commission,
+					});
+				}
+			}
+
 			Self::do_remove_nominator(stash);
 			Self::do_add_validator(stash, prefs.clone());
-			Self::deposit_event(Event::<T>::ValidatorPrefsSet { stash: ledger.stash, prefs });
+			Self::deposit_event(Event::<T>::ValidatorPrefsSet {
+				stash: ledger.stash,
+				old_prefs,
+				new_prefs: prefs,
+			});
 
 			Ok(())
 		}
@@ -1316,6 +1825,7 @@ pub mod pallet {
 			};
 
 			Self::do_remove_validator(stash);
+			NominationExpiry::<T>::remove(stash);
 			Self::do_add_nominator(stash, nominations);
 			Ok(())
 		}
@@ -1326,6 +1836,9 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
 		///
+		/// Emits `ChilledWithRole`, preceded by `Chilled` if the stash was validating or
+		/// nominating.
+		///
 		/// ## Complexity
 		/// - Independent of the arguments. Insignificant complexity.
 		/// - Contains one read.
@@ -1337,15 +1850,27 @@ pub mod pallet {
 
 			let ledger = Self::ledger(StakingAccount::Controller(controller))?;
 
-			Self::chill_stash(&ledger.stash);
+			let had_targets = Nominators::<T>::get(&ledger.stash).map(|n| n.targets.len() as u32);
+			let role = Self::chill_stash(&ledger.stash);
+			Self::deposit_event(Event::<T>::ChilledWithRole { stash: ledger.stash.clone(), role });
+			if let Some(had_targets) = had_targets {
+				Self::deposit_event(Event::<T>::NominationsCleared {
+					nominator: ledger.stash,
+					had_targets,
+				});
+			}
 			Ok(())
 		}
 
 		/// (Re-)set the payment target for a controller.
 		///
-		/// Effects will be felt instantly (as soon as this function is completed successfully).
+		/// Effects will be felt instantly (as soon as this function is completed successfully),
+		/// unless [`Config::PayeeChangeDelay`] is set. In that case, the change is stored in
+		/// [`PendingPayee`] and takes effect at the start of era `current_era + delay`, replacing
+		/// any previously scheduled change.
 		///
-		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
+		/// The dispatch origin for this call must be `T::PayeeControlOrigin`, which by default
+		/// requires it to be _Signed_ by the controller, not the stash.
 		///
 		/// ## Complexity
 		/// - O(1)
@@ -1359,7 +1884,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			payee: RewardDestination<T::AccountId>,
 		) -> DispatchResult {
-			let controller = ensure_signed(origin)?;
+			let controller = T::PayeeControlOrigin::ensure_origin(origin)?;
 			let ledger = Self::ledger(Controller(controller.clone()))?;
 
 			ensure!(
@@ -1370,9 +1895,27 @@ pub mod pallet {
 				Error::<T>::ControllerDeprecated
 			);
 
-			let _ = ledger
-				.set_payee(payee)
-				.defensive_proof("ledger was retrieved from storage, thus its bonded; qed.")?;
+			// virtual stakers are not allowed to have their rewards paid back to their stash.
+			ensure!(
+				!Self::is_virtual_staker(&ledger.stash) ||
+					!Self::is_self_payee(&ledger.stash, &payee),
+				Error::<T>::RewardDestinationRestricted
+			);
+			Self::ensure_payee_available(&ledger.stash, &payee)?;
+
+			match T::PayeeChangeDelay::get() {
+				Some(delay) => {
+					let era = Self::current_era().unwrap_or(0).defensive_saturating_add(delay);
+					PendingPayee::<T>::insert(&ledger.stash, (era, payee.clone()));
+					Self::deposit_event(Event::<T>::PayeeChangeScheduled {
+						stash: ledger.stash,
+						payee,
+						era,
+					});
+				},
+				None => Self::do_set_payee(ledger, payee)
+					.defensive_proof("ledger was retrieved from storage, thus its bonded; qed.")?,
+			}
 
 			Ok(())
 		}
@@ -1492,6 +2035,8 @@ pub mod pallet {
 		/// Thus the election process may be ongoing when this is called. In this case the
 		/// election will continue until the next era is triggered.
 		///
+		/// Emits `ForceEra`.
+		///
 		/// ## Complexity
 		/// - No arguments.
 		/// - Weight: O(1)
@@ -1514,6 +2059,8 @@ pub mod pallet {
 		/// If this is called just before a new era is triggered, the election process may not
 		/// have enough blocks to get a result.
 		///
+		/// Emits `ForceEra`.
+		///
 		/// ## Complexity
 		/// - No arguments.
 		/// - Weight: O(1)
@@ -1571,6 +2118,8 @@ pub mod pallet {
 		/// The election process starts multiple blocks before the end of the era.
 		/// If this is called just before a new era is triggered, the election process may not
 		/// have enough blocks to get a result.
+		///
+		/// Emits `ForceEra`.
 		#[pallet::call_index(16)]
 		#[pallet::weight(T::WeightInfo::force_new_era_always())]
 		pub fn force_new_era_always(origin: OriginFor<T>) -> DispatchResult {
@@ -1629,8 +2178,8 @@ pub mod pallet {
 			validator_stash: T::AccountId,
 			era: EraIndex,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
-			Self::do_payout_stakers(validator_stash, era)
+			let caller = ensure_signed(origin)?;
+			Self::do_payout_stakers(validator_stash, era, Some(caller))
 		}
 
 		/// Rebond a portion of the stash scheduled to be unlocked.
@@ -1648,6 +2197,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let controller = ensure_signed(origin)?;
 			let ledger = Self::ledger(Controller(controller))?;
+			ensure!(!Self::is_restricted(&ledger.stash), Error::<T>::Restricted);
 			ensure!(!ledger.unlocking.is_empty(), Error::<T>::NoUnlockChunk);
 
 			let initial_unlocking = ledger.unlocking.len() as u32;
@@ -1864,7 +2414,7 @@ pub mod pallet {
 			// * A `ChillThreshold` is set which defines how close to the max nominators or
 			//   validators we must reach before users can start chilling one-another.
 			// * A `MaxNominatorCount` and `MaxValidatorCount` which is used to determine how close
-			//   we are to the threshold.
+			//   we are to the threshold. Not needed if `ChillOtherRequiresCountLimit` is false.
 			// * A `MinNominatorBond` and `MinValidatorBond` which is the final condition checked to
 			//   determine this is a person that should be chilled because they have not met the
 			//   threshold bond required.
@@ -1878,23 +2428,28 @@ pub mod pallet {
 
 			if caller != controller {
 				let threshold = ChillThreshold::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
+				let requires_count_limit = T::ChillOtherRequiresCountLimit::get();
 				let min_active_bond = if Nominators::<T>::contains_key(&stash) {
-					let max_nominator_count =
-						MaxNominatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
-					let current_nominator_count = Nominators::<T>::count();
-					ensure!(
-						threshold * max_nominator_count < current_nominator_count,
-						Error::<T>::CannotChillOther
-					);
+					if requires_count_limit {
+						let max_nominator_count =
+							MaxNominatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
+						let current_nominator_count = Nominators::<T>::count();
+						ensure!(
+							threshold * max_nominator_count < current_nominator_count,
+							Error::<T>::CannotChillOther
+						);
+					}
 					MinNominatorBond::<T>::get()
 				} else if Validators::<T>::contains_key(&stash) {
-					let max_validator_count =
-						MaxValidatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
-					let current_validator_count = Validators::<T>::count();
-					ensure!(
-						threshold * max_validator_count < current_validator_count,
-						Error::<T>::CannotChillOther
-					);
+					if requires_count_limit {
+						let max_validator_count =
+							MaxValidatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
+						let current_validator_count = Validators::<T>::count();
+						ensure!(
+							threshold * max_validator_count < current_validator_count,
+							Error::<T>::CannotChillOther
+						);
+					}
 					MinValidatorBond::<T>::get()
 				} else {
 					Zero::zero()
@@ -1967,8 +2522,8 @@ pub mod pallet {
 			era: EraIndex,
 			page: Page,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
-			Self::do_payout_stakers_by_page(validator_stash, era, page)
+			let caller = ensure_signed(origin)?;
+			Self::do_payout_stakers_by_page(validator_stash, era, page, Some(caller))
 		}
 
 		/// Migrates an account's `RewardDestination::Controller` to
@@ -2152,6 +2707,676 @@ pub mod pallet {
 			);
 			Ok(())
 		}
+
+		/// Remove the slashing spans of a stash that is no longer bonded, if they ended before
+		/// the start of the current bonding window.
+		///
+		/// It can be called by anyone. Fails with `AlreadyBonded` if the stash is still bonded.
+		///
+		/// ## Parameters
+		///
+		/// - `num_slashing_spans`: Refer to comments on [`Call::withdraw_unbonded`] for more
+		/// details.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::prune_slashing_spans(*num_slashing_spans))]
+		pub fn prune_slashing_spans(
+			origin: OriginFor<T>,
+			stash: T::AccountId,
+			num_slashing_spans: u32,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			ensure!(
+				!StakingLedger::<T>::is_bonded(StakingAccount::Stash(stash.clone())),
+				Error::<T>::AlreadyBonded
+			);
+
+			let window_start = Self::active_era()
+				.map(|e| e.index)
+				.unwrap_or_default()
+				.saturating_sub(T::BondingDuration::get());
+			let pruned =
+				slashing::prune_stash_metadata::<T>(&stash, window_start, num_slashing_spans)?;
+
+			Ok(Some(T::WeightInfo::prune_slashing_spans(pruned)).into())
+		}
+
+		/// Declare the desire to nominate `targets` for the origin controller, until
+		/// `expiry_era`.
+		///
+		/// Behaves like [`Call::nominate`], except that the stash is chilled once `expiry_era`
+		/// becomes active. Calling [`Call::nominate`] afterwards removes the expiry.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
+		#[pallet::call_index(31)]
+		#[pallet::weight(
+			T::WeightInfo::nominate(targets.len() as u32)
+				.saturating_add(T::DbWeight::get().writes(1))
+		)]
+		pub fn nominate_until(
+			origin: OriginFor<T>,
+			targets: Vec<AccountIdLookupOf<T>>,
+			expiry_era: EraIndex,
+		) -> DispatchResult {
+			let controller = ensure_signed(origin.clone())?;
+
+			let active_era = Self::active_era().map(|e| e.index).unwrap_or_default();
+			ensure!(expiry_era > active_era, Error::<T>::InvalidNominationExpiry);
+
+			Self::nominate(origin, targets)?;
+
+			let ledger = Self::ledger(StakingAccount::Controller(controller))?;
+			NominationExpiry::<T>::insert(&ledger.stash, expiry_era);
+			Ok(())
+		}
+
+		/// Restrict `who` from bonding any funds.
+		///
+		/// A restricted account can still chill, unbond and withdraw its existing bond. The
+		/// dispatch origin must be `T::AdminOrigin`.
+		///
+		/// Emits `AccountRestricted`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::restrict_account())]
+		pub fn restrict_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			RestrictedAccounts::<T>::insert(&who, ());
+			Self::deposit_event(Event::<T>::AccountRestricted { who });
+			Ok(())
+		}
+
+		/// Lift the restriction set through [`Call::restrict_account`] on `who`.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		///
+		/// Emits `AccountUnrestricted`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::unrestrict_account())]
+		pub fn unrestrict_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			RestrictedAccounts::<T>::remove(&who);
+			Self::deposit_event(Event::<T>::AccountUnrestricted { who });
+			Ok(())
+		}
+
+		/// Withdraw the unlocked chunks like [`Call::withdraw_unbonded`] and, if the remaining
+		/// active bond is at least `MinValidatorBond`, declare the desire to validate with `prefs`
+		/// like [`Call::validate`].
+		///
+		/// The validate step is skipped if the remaining active bond is not enough. If it is
+		/// taken and fails, nothing is withdrawn either.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller.
+		///
+		/// ## Parameters
+		///
+		/// - `num_slashing_spans`: Refer to comments on [`Call::withdraw_unbonded`] for more
+		/// details.
+		#[pallet::call_index(34)]
+		#[pallet::weight(
+			T::WeightInfo::withdraw_unbonded_kill(*num_slashing_spans)
+				.saturating_add(Pallet::<T>::validate_weight())
+		)]
+		pub fn withdraw_and_revalidate(
+			origin: OriginFor<T>,
+			num_slashing_spans: u32,
+			prefs: ValidatorPrefs,
+		) -> DispatchResultWithPostInfo {
+			let controller = ensure_signed(origin.clone())?;
+
+			let withdraw_weight = Self::do_withdraw_unbonded(&controller, num_slashing_spans)?;
+
+			// the ledger is gone if everything has been withdrawn.
+			let can_validate = Self::ledger(Controller(controller))
+				.map_or(false, |ledger| ledger.active >= MinValidatorBond::<T>::get());
+			if !can_validate {
+				return Ok(Some(withdraw_weight).into())
+			}
+
+			Self::validate(origin, prefs)?;
+			Ok(Some(withdraw_weight.saturating_add(Self::validate_weight())).into())
+		}
+
+		/// Overrides [`Config::SessionsPerEra`], or removes the override if `value` is `None`.
+		///
+		/// The value is clamped between 1 and 256 sessions. It only takes effect from the next
+		/// planned era, so that the ongoing one is neither cut short nor stretched.
+		///
+		/// The dispatch origin must be Root.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_sessions_per_era())]
+		pub fn set_sessions_per_era(
+			origin: OriginFor<T>,
+			value: Option<SessionIndex>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let value = value.map(|v| v.clamp(MIN_SESSIONS_PER_ERA, MAX_SESSIONS_PER_ERA));
+			PendingSessionsPerEraOverride::<T>::put(value);
+			Self::deposit_event(Event::<T>::SessionsPerEraSet { value });
+			Ok(())
+		}
+
+		/// Chill a batch of stakers, under the same conditions as [`Call::chill_other`].
+		///
+		/// The conditions are evaluated once for the whole batch, based on the number of
+		/// nominators and validators before any of the `targets` is chilled. Targets that cannot
+		/// be chilled are skipped.
+		///
+		/// The dispatch origin for this call must be _Signed_, but can be called by anyone.
+		///
+		/// Emits [`Event::Chilled`] for every chilled staker and [`Event::ChilledBatch`] once,
+		/// followed by [`Event::ChilledBatchSkipped`] if any of the targets has been skipped.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::chill_other().saturating_mul(targets.len() as u64))]
+		pub fn chill_other_batch(
+			origin: OriginFor<T>,
+			targets: BoundedVec<T::AccountId, T::MaxChillOtherBatch>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+
+			let threshold = ChillThreshold::<T>::get();
+			let requires_count_limit = T::ChillOtherRequiresCountLimit::get();
+			let threshold_reached = |max_count: Option<u32>, current_count: u32| {
+				threshold.map_or(false, |t| {
+					!requires_count_limit || max_count.map_or(false, |max| t * max < current_count)
+				})
+			};
+			let nominators_chillable =
+				threshold_reached(MaxNominatorsCount::<T>::get(), Nominators::<T>::count());
+			let validators_chillable =
+				threshold_reached(MaxValidatorsCount::<T>::get(), Validators::<T>::count());
+			let min_nominator_bond = MinNominatorBond::<T>::get();
+			let min_validator_bond = MinValidatorBond::<T>::get();
+
+			let mut count: u32 = 0;
+			let mut skipped = Vec::new();
+			for stash in targets {
+				let Ok(ledger) = Self::ledger(Stash(stash.clone())) else {
+					skipped.push(stash);
+					continue
+				};
+				let is_nominator = Nominators::<T>::contains_key(&stash);
+				let is_validator = Validators::<T>::contains_key(&stash);
+
+				let can_chill = (is_nominator || is_validator) &&
+					(ledger.controller().as_ref() == Some(&caller) ||
+						(is_nominator && Nominators::<T>::get(&stash).is_none()) ||
+						(is_nominator &&
+							nominators_chillable && ledger.active < min_nominator_bond) ||
+						(is_validator &&
+							validators_chillable && ledger.active < min_validator_bond));
+				if can_chill {
+					Self::chill_stash(&stash);
+					count += 1;
+				} else {
+					skipped.push(stash);
+				}
+			}
+
+			Self::deposit_event(Event::<T>::ChilledBatch { count });
+
+			// skipped targets only cost the reads of their bond and roles.
+			let skipped_count = skipped.len() as u64;
+			if !skipped.is_empty() {
+				Self::deposit_event(Event::<T>::ChilledBatchSkipped { stashes: skipped });
+			}
+			Ok(Some(
+				T::WeightInfo::chill_other()
+					.saturating_mul(count.into())
+					.saturating_add(T::DbWeight::get().reads(4u64.saturating_mul(skipped_count))),
+			)
+			.into())
+		}
+
+		/// Cancel the payee change scheduled through [`Call::set_payee`], see
+		/// [`Config::PayeeChangeDelay`].
+		///
+		/// The dispatch origin for this call must be `T::PayeeControlOrigin`, like for
+		/// [`Call::set_payee`].
+		///
+		/// Emits [`Event::PayeeChangeCancelled`].
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::cancel_payee_change())]
+		pub fn cancel_payee_change(origin: OriginFor<T>) -> DispatchResult {
+			let controller = T::PayeeControlOrigin::ensure_origin(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+
+			ensure!(
+				PendingPayee::<T>::take(&ledger.stash).is_some(),
+				Error::<T>::NoPendingPayeeChange
+			);
+
+			Self::deposit_event(Event::<T>::PayeeChangeCancelled { stash: ledger.stash });
+			Ok(())
+		}
+
+		/// Rebond up to `value` of the chunk of the stash scheduled to be unlocked in `era`,
+		/// leaving the other unlocking chunks untouched.
+		///
+		/// Unlike [`Call::rebond`], which rebonds the most recent chunks first, this allows keeping
+		/// the chunks closest to maturity.
+		///
+		/// The dispatch origin must be signed by the controller.
+		///
+		/// Emits `Bonded`.
+		///
+		/// ## Complexity
+		/// - Time complexity: O(L), where L is unlocking chunks
+		/// - Bounded by `MaxUnlockingChunks`.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::rebond(T::MaxUnlockingChunks::get() as u32))]
+		pub fn rebond_chunk(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			#[pallet::compact] value: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+			ensure!(!Self::is_restricted(&ledger.stash), Error::<T>::Restricted);
+
+			let (ledger, rebonded_value) =
+				ledger.rebond_chunk(era, value).ok_or(Error::<T>::NoUnlockChunk)?;
+			// Last check: the new active amount of ledger must be more than ED.
+			ensure!(ledger.active >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
+
+			Self::deposit_event(Event::<T>::Bonded {
+				stash: ledger.stash.clone(),
+				amount: rebonded_value,
+			});
+
+			let stash = ledger.stash.clone();
+
+			// NOTE: ledger must be updated prior to calling `Self::weight_of`.
+			ledger.update()?;
+			if T::VoterList::contains(&stash) {
+				let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash)).defensive();
+			}
+
+			Ok(Some(T::WeightInfo::rebond(1)).into())
+		}
+
+		/// Transfer `amount` from `escrow` to the stash of a ledger and bond it as extra in the
+		/// same call, e.g. to compound rewards that were paid to `escrow` through
+		/// [`RewardDestination::Account`].
+		///
+		/// The dispatch origin for this call must be `T::EscrowBondOrigin`, which by default
+		/// requires it to be _Signed_ by the controller. `escrow` must have approved the stash
+		/// through [`Call::set_escrow_approval`].
+		///
+		/// Emits `BondedExtra`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::bond_extra_from_escrow())]
+		pub fn bond_extra_from_escrow(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+			escrow: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let controller = T::EscrowBondOrigin::ensure_origin(origin)?;
+			let escrow = T::Lookup::lookup(escrow)?;
+			let stash = Self::ledger(Controller(controller))?.stash;
+
+			ensure!(!Self::is_restricted(&stash), Error::<T>::Restricted);
+			// the funds of virtual stakers are not held by this pallet.
+			ensure!(!Self::is_virtual_staker(&stash), Error::<T>::VirtualStakerNotAllowed);
+			ensure!(
+				EscrowApprovals::<T>::contains_key(&escrow, &stash),
+				Error::<T>::EscrowNotApproved
+			);
+
+			T::Currency::transfer(&escrow, &stash, amount, ExistenceRequirement::KeepAlive)?;
+			Self::do_bond_extra(&stash, amount)
+		}
+
+		/// Allow or disallow `stash` to bond the funds of the origin through
+		/// [`Call::bond_extra_from_escrow`].
+		///
+		/// The dispatch origin for this call must be _Signed_ by the escrow account.
+		///
+		/// Emits `EscrowApprovalSet`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_escrow_approval())]
+		pub fn set_escrow_approval(
+			origin: OriginFor<T>,
+			stash: AccountIdLookupOf<T>,
+			approved: bool,
+		) -> DispatchResult {
+			let escrow = ensure_signed(origin)?;
+			let stash = T::Lookup::lookup(stash)?;
+
+			if approved {
+				EscrowApprovals::<T>::insert(&escrow, &stash, ());
+			} else {
+				EscrowApprovals::<T>::remove(&escrow, &stash);
+			}
+
+			Self::deposit_event(Event::<T>::EscrowApprovalSet { escrow, stash, approved });
+			Ok(())
+		}
+
+		/// Sets the ideal number of validators and the minimum number of validators together.
+		///
+		/// Both are rejected unless `1 <= min <= count <= ElectionProviderBase::MaxWinners`.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// ## Complexity
+		/// O(1)
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::set_validator_count_and_min())]
+		pub fn set_validator_count_and_min(
+			origin: OriginFor<T>,
+			#[pallet::compact] count: u32,
+			#[pallet::compact] min: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				count <= <T::ElectionProvider as ElectionProviderBase>::MaxWinners::get(),
+				Error::<T>::TooManyValidators
+			);
+			ensure!(min >= 1 && min <= count, Error::<T>::InvalidMinimumValidatorCount);
+
+			ValidatorCount::<T>::put(count);
+			MinimumValidatorCount::<T>::put(min);
+			Ok(())
+		}
+
+		/// Unbond and eventually reap a stash that has been idle for at least
+		/// [`Config::IdleReapEras`] eras and is not exposed in any recent era.
+		///
+		/// Only the stashes in [`ReapableStashes`] can be reaped, stashes bonded by other pallets
+		/// are left alone.
+		///
+		/// The first call unbonds the whole active balance of the stash. Once the bonding duration
+		/// has passed, a second call withdraws the unlocked funds and reaps the stash.
+		///
+		/// It can be called by anyone.
+		///
+		/// ## Parameters
+		///
+		/// - `num_slashing_spans`: Refer to comments on [`Call::withdraw_unbonded`] for more
+		/// details.
+		#[pallet::call_index(42)]
+		#[pallet::weight(
+			T::WeightInfo::chill()
+				.saturating_add(T::WeightInfo::unbond())
+				.saturating_add(T::WeightInfo::withdraw_unbonded_kill(*num_slashing_spans))
+		)]
+		pub fn reap_idle(
+			origin: OriginFor<T>,
+			stash: T::AccountId,
+			num_slashing_spans: u32,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let reap_eras = T::IdleReapEras::get().ok_or(Error::<T>::IdleReapDisabled)?;
+
+			// virtual stakers should not be allowed to be reaped.
+			ensure!(!Self::is_virtual_staker(&stash), Error::<T>::VirtualStakerNotAllowed);
+
+			let idle_since = IdleSince::<T>::get(&stash).ok_or(Error::<T>::NotIdleLongEnough)?;
+			let active_era = Self::active_era().map(|e| e.index).unwrap_or_default();
+			ensure!(
+				active_era >= idle_since.saturating_add(reap_eras) &&
+					!Self::is_exposed_in_any_recent_era(&stash),
+				Error::<T>::NotIdleLongEnough
+			);
+
+			let ledger = Self::ledger(Stash(stash.clone()))?;
+			let controller = Self::bonded(&stash).ok_or(Error::<T>::NotStash)?;
+			let actual_weight = if !ledger.active.is_zero() {
+				Self::unbond(frame_system::RawOrigin::Signed(controller).into(), ledger.active)?
+					.actual_weight
+					.unwrap_or_else(T::WeightInfo::unbond)
+			} else {
+				Self::do_withdraw_unbonded(&controller, num_slashing_spans)?
+			};
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Force a batch of stakers to become completely unstaked, immediately, like
+		/// [`Call::force_unstake`].
+		///
+		/// Each entry is a stash and its `num_slashing_spans`. Entries that cannot be unstaked,
+		/// e.g. because of a wrong number of slashing spans, are skipped without affecting the
+		/// others.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits [`Event::ForceUnstakeFailed`] for every skipped entry and
+		/// [`Event::ForceUnstakedBatch`] once.
+		#[pallet::call_index(43)]
+		#[pallet::weight(stashes.iter().fold(Weight::zero(), |weight, (_, num_slashing_spans)| {
+			weight.saturating_add(T::WeightInfo::force_unstake(*num_slashing_spans))
+		}))]
+		pub fn force_unstake_batch(
+			origin: OriginFor<T>,
+			stashes: BoundedVec<(T::AccountId, u32), T::MaxForceUnstakeBatch>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let (mut unstaked, mut failed) = (0u32, 0u32);
+			let mut actual_weight = Weight::zero();
+			for (stash, num_slashing_spans) in stashes {
+				match frame_support::storage::with_storage_layer(|| {
+					Self::kill_stash(&stash, num_slashing_spans)
+				}) {
+					Ok(()) => {
+						unstaked += 1;
+						actual_weight = actual_weight
+							.saturating_add(T::WeightInfo::force_unstake(num_slashing_spans));
+					},
+					Err(error) => {
+						failed += 1;
+						// failed entries only cost the reads of their slashing spans and bond.
+						actual_weight = actual_weight.saturating_add(T::DbWeight::get().reads(2));
+						Self::deposit_event(Event::<T>::ForceUnstakeFailed { stash, error });
+					},
+				}
+			}
+
+			Self::deposit_event(Event::<T>::ForceUnstakedBatch { unstaked, failed });
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Remove any unlocked chunks from the `unlocking` queue, like
+		/// [`Call::withdraw_unbonded`], and transfer the freed funds from the stash to `dest`.
+		///
+		/// The stash is reaped if its ledger drops below the existential deposit, in which case all
+		/// of its remaining bond is sent to `dest`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller. Virtual stakers
+		/// are not allowed.
+		///
+		/// Emits `Withdrawn` and `WithdrawnTo`.
+		///
+		/// ## Parameters
+		///
+		/// - `num_slashing_spans`: Refer to comments on [`Call::withdraw_unbonded`] for more
+		/// details.
+		#[pallet::call_index(44)]
+		#[pallet::weight(
+			T::WeightInfo::withdraw_unbonded_kill(*num_slashing_spans)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn withdraw_unbonded_to(
+			origin: OriginFor<T>,
+			num_slashing_spans: u32,
+			dest: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller.clone()))?;
+			let stash = ledger.stash.clone();
+
+			// the funds of virtual stakers are not held by this pallet.
+			ensure!(!Self::is_virtual_staker(&stash), Error::<T>::VirtualStakerNotAllowed);
+
+			let withdraw_weight = Self::do_withdraw_unbonded(&controller, num_slashing_spans)?;
+			let remaining = Self::ledger(Stash(stash.clone())).map(|l| l.total).unwrap_or_default();
+			let amount = ledger.total.saturating_sub(remaining);
+
+			if !amount.is_zero() {
+				T::Currency::transfer(&stash, &dest, amount, ExistenceRequirement::AllowDeath)?;
+				Self::deposit_event(Event::<T>::WithdrawnTo { stash, amount, dest });
+			}
+
+			Ok(Some(withdraw_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))).into())
+		}
+
+		/// Set or remove the limit on how fast validators can raise their commission.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// ## Complexity
+		/// O(1)
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_commission_change_rate())]
+		pub fn set_commission_change_rate(
+			origin: OriginFor<T>,
+			limit: Option<CommissionChangeRate>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			CommissionChangeRateLimit::<T>::set(limit);
+			Ok(())
+		}
+
+		/// Add `amount` to the active bond of the stash, rebonding its unlocking chunks first and
+		/// only bonding the remainder from its free balance, as [`Call::bond_extra`] does.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stash, not the controller.
+		///
+		/// Emits `Bonded` for the rebonded part and `BondedExtra` for the part bonded from the free
+		/// balance, if any.
+		#[pallet::call_index(46)]
+		#[pallet::weight(
+			T::WeightInfo::rebond(T::MaxUnlockingChunks::get())
+				.saturating_add(T::WeightInfo::bond_extra())
+		)]
+		pub fn bond_extra_prefer_unlocking(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let stash = ensure_signed(origin)?;
+			ensure!(!Self::is_restricted(&stash), Error::<T>::Restricted);
+
+			let (ledger, rebonded) = Self::ledger(Stash(stash.clone()))?.rebond(amount);
+			if !rebonded.is_zero() {
+				Self::deposit_event(Event::<T>::Bonded { stash: stash.clone(), amount: rebonded });
+			}
+			let new_active = ledger.active;
+			ledger.update()?;
+
+			let remainder = amount.defensive_saturating_sub(rebonded);
+			if !remainder.is_zero() {
+				return Self::do_bond_extra(&stash, remainder)
+			}
+
+			// the new active amount of ledger must be more than ED.
+			ensure!(new_active >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
+			if T::VoterList::contains(&stash) {
+				let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash)).defensive();
+			}
+			Ok(())
+		}
+
+		/// Pay out a batch of pages, each given as a validator stash, an era and a page, like
+		/// [`Call::payout_stakers_by_page`].
+		///
+		/// Pages that are already claimed are skipped, any other failure aborts the whole batch.
+		///
+		/// The origin of this call must be _Signed_. Any account can call this function, even if
+		/// it is not one of the stakers.
+		#[pallet::call_index(47)]
+		#[pallet::weight(
+			T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+				.saturating_mul(payouts.len() as u64)
+		)]
+		pub fn payout_multiple(
+			origin: OriginFor<T>,
+			payouts: BoundedVec<(T::AccountId, EraIndex, Page), T::MaxPayoutBatch>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+
+			let mut actual_weight = Weight::zero();
+			for (validator_stash, era, page) in payouts {
+				let result = frame_support::storage::with_storage_layer(|| {
+					Self::do_payout_stakers_by_page(
+						validator_stash,
+						era,
+						page,
+						Some(caller.clone()),
+					)
+				});
+				let post_info = match result {
+					Ok(post_info) => post_info,
+					Err(e) if e.error == Error::<T>::AlreadyClaimed.into() => e.post_info,
+					Err(mut e) => {
+						actual_weight.saturating_accrue(
+							e.post_info
+								.actual_weight
+								.unwrap_or(T::WeightInfo::payout_stakers_alive_staked(0)),
+						);
+						e.post_info.actual_weight = Some(actual_weight);
+						return Err(e)
+					},
+				};
+				actual_weight.saturating_accrue(post_info.actual_weight.unwrap_or(
+					T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()),
+				));
+			}
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Set or remove the minimum stake a nominator must back the validator with to be part of
+		/// its exposure, see [`ValidatorNominationFilter`].
+		///
+		/// The filter is applied when the next election result is stored, the current exposures
+		/// are not affected. Only validators can set a filter, but it can always be removed.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
+		///
+		/// Emits [`Event::MinNominatorStakeSet`].
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::set_min_nominator_stake())]
+		pub fn set_min_nominator_stake(
+			origin: OriginFor<T>,
+			min: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+			let stash = ledger.stash;
+			ensure!(
+				min.is_none() || Validators::<T>::contains_key(&stash),
+				Error::<T>::NotValidator
+			);
+
+			ValidatorNominationFilter::<T>::set(&stash, min);
+			Self::deposit_event(Event::<T>::MinNominatorStakeSet { stash, min });
+			Ok(())
+		}
+
+		/// Add up to `max_additional` from the given `source` of the stash balance to its bond.
+		///
+		/// [`FundsSource::Free`] behaves like [`Call::bond_extra`].
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stash, not the controller.
+		///
+		/// Emits `BondedExtra`.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::bond_extra())]
+		pub fn bond_extra_from(
+			origin: OriginFor<T>,
+			source: FundsSource,
+			#[pallet::compact] max_additional: BalanceOf<T>,
+		) -> DispatchResult {
+			let stash = ensure_signed(origin)?;
+			ensure!(!Self::is_restricted(&stash), Error::<T>::Restricted);
+
+			let additional = match source {
+				FundsSource::Free => max_additional,
+			};
+
+			Self::do_bond_extra(&stash, additional)
+		}
 	}
 }
 
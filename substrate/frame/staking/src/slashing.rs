@@ -51,7 +51,7 @@
 
 use crate::{
 	BalanceOf, Config, DisabledValidators, DisablingStrategy, Error, Exposure, NegativeImbalanceOf,
-	NominatorSlashInEra, Pallet, Perbill, SessionInterface, SpanSlash, UnappliedSlash,
+	NominatorSlashInEra, Pallet, Perbill, SessionInterface, SlashScope, SpanSlash, UnappliedSlash,
 	ValidatorSlashInEra,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -62,7 +62,7 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Saturating, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchError, DispatchResult, RuntimeDebug,
 };
 use sp_staking::{EraIndex, StakingInterface};
 use sp_std::vec::Vec;
@@ -220,6 +220,8 @@ pub(crate) struct SlashParams<'a, T: 'a + Config> {
 	/// The maximum percentage of a slash that ever gets paid out.
 	/// This is f_inf in the paper.
 	pub(crate) reward_proportion: Perbill,
+	/// Whether the nominators are slashed along with the validator.
+	pub(crate) scope: SlashScope,
 }
 
 /// Computes a slash of a validator and nominators. It returns an unapplied
@@ -236,7 +238,11 @@ pub(crate) fn compute_slash<T: Config>(
 
 	// is the slash amount here a maximum for the era?
 	let own_slash = params.slash * params.exposure.own;
-	if params.slash * params.exposure.total == Zero::zero() {
+	let slashable = match params.scope {
+		SlashScope::Full => params.exposure.total,
+		SlashScope::ValidatorOnly => params.exposure.own,
+	};
+	if params.slash * slashable == Zero::zero() {
 		// kick out the validator even if they won't be slashed,
 		// as long as the misbehavior is from their most recent slashing span.
 		kick_out_if_recent::<T>(params);
@@ -287,7 +293,10 @@ pub(crate) fn compute_slash<T: Config>(
 	add_offending_validator::<T>(&params);
 
 	let mut nominators_slashed = Vec::new();
-	reward_payout += slash_nominators::<T>(params.clone(), prior_slash_p, &mut nominators_slashed);
+	if params.scope == SlashScope::Full {
+		reward_payout +=
+			slash_nominators::<T>(params.clone(), prior_slash_p, &mut nominators_slashed);
+	}
 
 	Some(UnappliedSlash {
 		validator: params.stash.clone(),
@@ -525,6 +534,40 @@ impl<'a, T: 'a + Config> Drop for InspectingSpans<'a, T> {
 	}
 }
 
+/// Prune the slashing spans of `stash` that ended before `window_start`, alongside their
+/// `SpanSlash` records.
+///
+/// Returns the number of spans that were removed.
+pub(crate) fn prune_stash_metadata<T: Config>(
+	stash: &T::AccountId,
+	window_start: EraIndex,
+	num_slashing_spans: u32,
+) -> Result<u32, DispatchError> {
+	let mut spans = match crate::SlashingSpans::<T>::get(stash) {
+		None => return Ok(0),
+		Some(s) => s,
+	};
+
+	ensure!(
+		num_slashing_spans as usize >= spans.iter().count(),
+		Error::<T>::IncorrectSlashingSpans
+	);
+
+	let pruned = match spans.prune(window_start) {
+		Some((start, end)) => {
+			for span_index in start..end {
+				SpanSlash::<T>::remove(&(stash.clone(), span_index));
+			}
+			end.saturating_sub(start)
+		},
+		None => 0,
+	};
+
+	crate::SlashingSpans::<T>::insert(stash, spans);
+
+	Ok(pruned)
+}
+
 /// Clear slashing metadata for an obsolete era.
 pub(crate) fn clear_era_metadata<T: Config>(obsolete_era: EraIndex) {
 	#[allow(deprecated)]
@@ -571,6 +614,20 @@ pub fn do_slash<T: Config>(
 	reward_payout: &mut BalanceOf<T>,
 	slashed_imbalance: &mut NegativeImbalanceOf<T>,
 	slash_era: EraIndex,
+) {
+	slash_staker::<T>(stash, value, reward_payout, slashed_imbalance, slash_era, slash_era)
+}
+
+// same as `do_slash`, but for an offence committed in `offence_era`, which can be earlier than
+// `slash_era` if the slash was deferred. The `Slashed` event refers to the offence era and the
+// slashing span of `stash` that contains it.
+fn slash_staker<T: Config>(
+	stash: &T::AccountId,
+	value: BalanceOf<T>,
+	reward_payout: &mut BalanceOf<T>,
+	slashed_imbalance: &mut NegativeImbalanceOf<T>,
+	slash_era: EraIndex,
+	offence_era: EraIndex,
 ) {
 	let mut ledger =
 		match Pallet::<T>::ledger(sp_staking::StakingAccount::Stash(stash.clone())).defensive() {
@@ -599,33 +656,46 @@ pub fn do_slash<T: Config>(
 		.update()
 		.defensive_proof("ledger fetched from storage so it exists in storage; qed.");
 
+	// spans that have been pruned, or never existed, are reported as the first span.
+	let span_index = crate::SlashingSpans::<T>::get(stash)
+		.and_then(|spans| spans.iter().find(|span| span.contains_era(offence_era)))
+		.map_or(0, |span| span.index);
+
 	// trigger the event
-	<Pallet<T>>::deposit_event(super::Event::<T>::Slashed { staker: stash.clone(), amount: value });
+	<Pallet<T>>::deposit_event(super::Event::<T>::Slashed {
+		staker: stash.clone(),
+		amount: value,
+		slash_era: offence_era,
+		span_index,
+	});
 }
 
-/// Apply a previously-unapplied slash.
+/// Apply a previously-unapplied slash for an offence committed in `offence_era`.
 pub(crate) fn apply_slash<T: Config>(
 	unapplied_slash: UnappliedSlash<T::AccountId, BalanceOf<T>>,
 	slash_era: EraIndex,
+	offence_era: EraIndex,
 ) {
 	let mut slashed_imbalance = NegativeImbalanceOf::<T>::zero();
 	let mut reward_payout = unapplied_slash.payout;
 
-	do_slash::<T>(
+	slash_staker::<T>(
 		&unapplied_slash.validator,
 		unapplied_slash.own,
 		&mut reward_payout,
 		&mut slashed_imbalance,
 		slash_era,
+		offence_era,
 	);
 
 	for &(ref nominator, nominator_slash) in &unapplied_slash.others {
-		do_slash::<T>(
+		slash_staker::<T>(
 			nominator,
 			nominator_slash,
 			&mut reward_payout,
 			&mut slashed_imbalance,
 			slash_era,
+			offence_era,
 		);
 	}
 
@@ -27,7 +27,10 @@ use frame_support::{
 	assert_noop, assert_ok, assert_storage_noop,
 	dispatch::{extract_actual_weight, GetDispatchInfo, WithPostDispatchInfo},
 	pallet_prelude::*,
-	traits::{Currency, Get, InspectLockableCurrency, ReservableCurrency},
+	traits::{
+		Currency, Get, InspectLockableCurrency, LockableCurrency, ReservableCurrency,
+		WithdrawReasons,
+	},
 };
 
 use mock::*;
@@ -39,7 +42,7 @@ use sp_runtime::{
 };
 use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
-	SessionIndex,
+	ExposureDelta, SessionIndex, StakerRole,
 };
 use sp_std::prelude::*;
 use substrate_test_utils::assert_eq_uvec;
@@ -99,6 +102,72 @@ fn set_staking_configs_works() {
 	});
 }
 
+#[test]
+fn preview_staking_configs_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::set_staking_configs(
+			RuntimeOrigin::root(),
+			ConfigOp::Set(1_500),
+			ConfigOp::Set(2_000),
+			ConfigOp::Set(10),
+			ConfigOp::Set(20),
+			ConfigOp::Set(Percent::from_percent(75)),
+			ConfigOp::Set(Perbill::from_percent(5)),
+			ConfigOp::Set(Percent::from_percent(50))
+		));
+
+		let preview = Staking::preview_staking_configs(
+			ConfigOp::Set(1_000),
+			ConfigOp::Noop,
+			ConfigOp::Remove,
+			ConfigOp::Set(30),
+			ConfigOp::Noop,
+			ConfigOp::Remove,
+			ConfigOp::Noop,
+		);
+		// nothing is changed.
+		assert_eq!(MinNominatorBond::<Test>::get(), 1_500);
+		assert_eq!(MaxNominatorsCount::<Test>::get(), Some(10));
+		assert_eq!(MinCommission::<Test>::get(), Perbill::from_percent(5));
+		assert_eq!(
+			preview,
+			StakingConfigPreview {
+				min_nominator_bond: 1_000,
+				min_validator_bond: 2_000,
+				max_nominator_count: None,
+				max_validator_count: Some(30),
+				chill_threshold: Some(Percent::from_percent(75)),
+				min_commission: Perbill::zero(),
+				max_staked_rewards: Some(Percent::from_percent(50)),
+			}
+		);
+
+		// the preview matches the outcome of the actual call.
+		assert_ok!(Staking::set_staking_configs(
+			RuntimeOrigin::root(),
+			ConfigOp::Set(1_000),
+			ConfigOp::Noop,
+			ConfigOp::Remove,
+			ConfigOp::Set(30),
+			ConfigOp::Noop,
+			ConfigOp::Remove,
+			ConfigOp::Noop
+		));
+		assert_eq!(
+			preview,
+			StakingConfigPreview {
+				min_nominator_bond: MinNominatorBond::<Test>::get(),
+				min_validator_bond: MinValidatorBond::<Test>::get(),
+				max_nominator_count: MaxNominatorsCount::<Test>::get(),
+				max_validator_count: MaxValidatorsCount::<Test>::get(),
+				chill_threshold: ChillThreshold::<Test>::get(),
+				min_commission: MinCommission::<Test>::get(),
+				max_staked_rewards: MaxStakedRewards::<Test>::get(),
+			}
+		);
+	});
+}
+
 #[test]
 fn force_unstake_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -127,6 +196,86 @@ fn force_unstake_works() {
 	});
 }
 
+#[test]
+fn force_unstake_batch_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// adds 2 slashing spans.
+		add_slash(&11);
+		let _ = staking_events_since_last_call();
+
+		let stashes: BoundedVec<_, MaxForceUnstakeBatch> =
+			bounded_vec![(11, 0), (21, 0), (1337, 0), (31, 0)];
+		assert_noop!(
+			Staking::force_unstake_batch(RuntimeOrigin::signed(11), stashes.clone()),
+			BadOrigin
+		);
+		assert_ok!(Staking::force_unstake_batch(RuntimeOrigin::root(), stashes));
+
+		// the entries with a wrong number of slashing spans or no bond are skipped.
+		assert_eq!(Staking::bonded(&11), Some(11));
+		assert_eq!(Staking::bonded(&21), None);
+		assert_eq!(Staking::bonded(&31), None);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::ForceUnstakeFailed {
+					stash: 11,
+					error: Error::<Test>::IncorrectSlashingSpans.into()
+				},
+				Event::ForceUnstakeFailed { stash: 1337, error: Error::<Test>::NotStash.into() },
+				Event::ForceUnstakedBatch { unstaked: 2, failed: 2 }
+			]
+		);
+
+		assert_ok!(Staking::force_unstake_batch(RuntimeOrigin::root(), bounded_vec![(11, 2)]));
+		assert_eq!(Staking::bonded(&11), None);
+	});
+}
+
+#[test]
+fn withdraw_unbonded_to_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		bond(61, 1000);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 400));
+		let _ = staking_events_since_last_call();
+
+		// nothing is unlocked yet.
+		assert_ok!(Staking::withdraw_unbonded_to(RuntimeOrigin::signed(61), 0, 1337));
+		assert_eq!(Balances::free_balance(1337), 0);
+		assert!(staking_events_since_last_call().is_empty());
+
+		// the unlocked chunk is sent to the destination.
+		mock::start_active_era(3);
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::withdraw_unbonded_to(RuntimeOrigin::signed(61), 0, 1337));
+		assert_eq!(Balances::free_balance(1337), 400);
+		assert_eq!(Balances::free_balance(61), 600);
+		assert_eq!(Staking::ledger(61.into()).unwrap().total, 600);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Withdrawn { stash: 61, amount: 400 },
+				Event::WithdrawnTo { stash: 61, amount: 400, dest: 1337 }
+			]
+		);
+
+		// the stash is reaped once fully withdrawn.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 600));
+		mock::start_active_era(6);
+		assert_ok!(Staking::withdraw_unbonded_to(RuntimeOrigin::signed(61), 0, 1337));
+		assert_eq!(Staking::bonded(&61), None);
+		assert_eq!(Balances::free_balance(1337), 1000);
+		assert_eq!(Balances::free_balance(61), 0);
+
+		// virtual stakers cannot withdraw to another account.
+		bond_virtual_nominator(71, 72, 500, vec![11]);
+		assert_noop!(
+			Staking::withdraw_unbonded_to(RuntimeOrigin::signed(71), 0, 1337),
+			Error::<Test>::VirtualStakerNotAllowed
+		);
+	});
+}
+
 #[test]
 fn kill_stash_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -353,13 +502,18 @@ fn rewards_should_work() {
 
 		assert_eq!(active_era(), 1);
 		assert_eq!(mock::RewardRemainderUnbalanced::get(), maximum_payout - total_payout_0,);
+		let events = mock::staking_events();
 		assert_eq!(
-			*mock::staking_events().last().unwrap(),
-			Event::EraPaid {
-				era_index: 0,
-				validator_payout: total_payout_0,
-				remainder: maximum_payout - total_payout_0
-			}
+			events[events.len() - 2..],
+			[
+				Event::EraPaid {
+					era_index: 0,
+					validator_payout: total_payout_0,
+					remainder: maximum_payout - total_payout_0,
+					total_staked: ErasTotalStake::<Test>::get(0),
+				},
+				Event::EraRewardPointsReset { era: 1 },
+			]
 		);
 		mock::make_all_reward_payment(0);
 
@@ -392,13 +546,18 @@ fn rewards_should_work() {
 			mock::RewardRemainderUnbalanced::get(),
 			maximum_payout * 2 - total_payout_0 - total_payout_1,
 		);
+		let events = mock::staking_events();
 		assert_eq!(
-			*mock::staking_events().last().unwrap(),
-			Event::EraPaid {
-				era_index: 1,
-				validator_payout: total_payout_1,
-				remainder: maximum_payout - total_payout_1
-			}
+			events[events.len() - 2..],
+			[
+				Event::EraPaid {
+					era_index: 1,
+					validator_payout: total_payout_1,
+					remainder: maximum_payout - total_payout_1,
+					total_staked: ErasTotalStake::<Test>::get(1),
+				},
+				Event::EraRewardPointsReset { era: 2 },
+			]
 		);
 		mock::make_all_reward_payment(1);
 
@@ -524,6 +683,125 @@ fn blocking_and_kicking_works() {
 		});
 }
 
+#[test]
+fn can_nominate_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// stash bond of 222 has a nomination quota of 2 targets.
+		bond(61, 222);
+		assert_eq!(Staking::api_nominations_quota(222), 2);
+
+		// valid nominations, without changing any state.
+		assert_storage_noop!(assert_ok!(Staking::can_nominate(&61, &[11, 21])));
+		assert!(!Nominators::<Test>::contains_key(61));
+
+		// not bonded.
+		assert_eq!(Staking::can_nominate(&62, &[11]), Err(Error::<Test>::NotStash));
+		// no targets.
+		assert_eq!(Staking::can_nominate(&61, &[]), Err(Error::<Test>::EmptyTargets));
+		// above the nomination quota.
+		assert_eq!(Staking::can_nominate(&61, &[11, 21, 31]), Err(Error::<Test>::TooManyTargets));
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(61), vec![11, 21, 31]),
+			Error::<Test>::TooManyTargets
+		);
+
+		// blocked target, unless already nominated.
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { blocked: true, ..Default::default() }
+		));
+		assert_eq!(Staking::can_nominate(&61, &[11]), Err(Error::<Test>::BadTarget));
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(61), vec![11]),
+			Error::<Test>::BadTarget
+		);
+		assert_ok!(Staking::can_nominate(&101, &[11]));
+
+		// too many nominators, unless already nominating.
+		MaxNominatorsCount::<Test>::put(Nominators::<Test>::count());
+		assert_eq!(Staking::can_nominate(&61, &[21]), Err(Error::<Test>::TooManyNominators));
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(61), vec![21]),
+			Error::<Test>::TooManyNominators
+		);
+		assert_ok!(Staking::can_nominate(&101, &[21]));
+		MaxNominatorsCount::<Test>::kill();
+
+		// insufficient bond.
+		MinNominatorBond::<Test>::put(223);
+		assert_eq!(Staking::can_nominate(&61, &[21]), Err(Error::<Test>::InsufficientBond));
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(61), vec![21]),
+			Error::<Test>::InsufficientBond
+		);
+	});
+}
+
+#[test]
+fn minimum_nominator_bond_effective_works() {
+	ExtBuilder::default().min_nominator_bond(100).build_and_execute(|| {
+		// 101 nominates with more than the minimum bond.
+		let active = Staking::ledger(101.into()).unwrap().active;
+		assert_eq!(active, 500);
+		assert_eq!(Staking::minimum_nominator_bond_effective(&101), 0);
+
+		// the minimum is raised above the bond of 101, which can still nominate.
+		MinNominatorBond::<Test>::put(800);
+		assert!(Nominators::<Test>::contains_key(101));
+		assert_eq!(Staking::minimum_nominator_bond_effective(&101), 800 - active);
+
+		// topping up the deficit is enough.
+		let _ = Balances::make_free_balance_be(&101, 2000);
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(101), 800 - active));
+		assert_eq!(Staking::minimum_nominator_bond_effective(&101), 0);
+
+		// not bonded.
+		assert_eq!(Staking::minimum_nominator_bond_effective(&7), 800);
+	});
+}
+
+#[test]
+fn can_validate_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		bond(61, 1000);
+		let prefs = ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false };
+
+		// valid, without changing any state.
+		assert_storage_noop!(assert_ok!(Staking::can_validate(&61, &prefs)));
+		assert!(!Validators::<Test>::contains_key(61));
+
+		// not bonded.
+		assert_eq!(Staking::can_validate(&62, &prefs), Err(Error::<Test>::NotStash));
+
+		// commission below the minimum.
+		MinCommission::<Test>::put(Perbill::from_percent(10));
+		assert_eq!(Staking::can_validate(&61, &prefs), Err(Error::<Test>::CommissionTooLow));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(61), prefs.clone()),
+			Error::<Test>::CommissionTooLow
+		);
+		MinCommission::<Test>::kill();
+
+		// too many validators, unless already validating.
+		MaxValidatorsCount::<Test>::put(Validators::<Test>::count());
+		assert_eq!(Staking::can_validate(&61, &prefs), Err(Error::<Test>::TooManyValidators));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(61), prefs.clone()),
+			Error::<Test>::TooManyValidators
+		);
+		assert_ok!(Staking::can_validate(&11, &prefs));
+		MaxValidatorsCount::<Test>::kill();
+
+		// insufficient bond.
+		MinValidatorBond::<Test>::put(1001);
+		assert_eq!(Staking::can_validate(&61, &prefs), Err(Error::<Test>::InsufficientBond));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(61), prefs),
+			Error::<Test>::InsufficientBond
+		);
+	});
+}
+
 #[test]
 fn less_than_needed_candidates_works() {
 	ExtBuilder::default()
@@ -777,6 +1055,95 @@ fn nominators_also_get_slashed_pro_rata() {
 		});
 }
 
+#[test]
+fn validator_only_slash_leaves_nominators_untouched() {
+	ExtBuilder::default()
+		.validator_count(4)
+		.set_status(41, StakerStatus::Validator)
+		.build_and_execute(|| {
+			mock::start_active_era(1);
+			let slash_percent = Perbill::from_percent(5);
+			let initial_exposure = Staking::eras_stakers(active_era(), &11);
+			// 101 is a nominator for 11
+			assert_eq!(initial_exposure.others.first().unwrap().who, 101);
+
+			let nominator_stake = Staking::ledger(101.into()).unwrap().active;
+			let nominator_balance = balances(&101).0;
+			let validator_stake = Staking::ledger(11.into()).unwrap().active;
+			let validator_balance = balances(&11).0;
+			let _ = staking_events_since_last_call();
+
+			// 11 goes offline, only its own stake is slashed.
+			Staking::on_offence_with_scope(
+				&[OffenceDetails { offender: (11, initial_exposure.clone()), reporters: vec![] }],
+				&[slash_percent],
+				Staking::eras_start_session_index(active_era()).unwrap(),
+				SlashScope::ValidatorOnly,
+			);
+
+			let validator_share = slash_percent * initial_exposure.own;
+			assert!(validator_share > 0);
+			assert_eq!(
+				Staking::ledger(11.into()).unwrap().active,
+				validator_stake - validator_share
+			);
+			assert_eq!(balances(&11).0, validator_balance - validator_share);
+
+			// the nominator is untouched.
+			assert_eq!(Staking::ledger(101.into()).unwrap().active, nominator_stake);
+			assert_eq!(balances(&101).0, nominator_balance);
+			assert_eq!(NominatorSlashInEra::<Test>::get(active_era(), 101), None);
+
+			// the events only reference the validator.
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![
+					Event::SlashReported { validator: 11, fraction: slash_percent, slash_era: 1 },
+					Event::Slashed {
+						staker: 11,
+						amount: validator_share,
+						slash_era: 1,
+						span_index: 0
+					},
+				]
+			);
+			// the offence is recorded in the slashing spans, and the validator disabled.
+			assert_eq!(
+				ValidatorSlashInEra::<Test>::get(active_era(), 11),
+				Some((slash_percent, validator_share))
+			);
+			assert!(SlashingSpans::<Test>::contains_key(11));
+			assert!(is_disabled(11));
+		});
+}
+
+#[test]
+fn deferred_validator_only_slash_leaves_nominators_untouched() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		let exposure = Staking::eras_stakers(active_era(), &11);
+		let nominator_balance = balances(&101).0;
+
+		Staking::on_offence_with_scope(
+			&[OffenceDetails { offender: (11, exposure), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+			Staking::eras_start_session_index(active_era()).unwrap(),
+			SlashScope::ValidatorOnly,
+		);
+
+		// the unapplied slash is recorded without any nominator.
+		let unapplied = UnappliedSlashes::<Test>::get(4);
+		assert_eq!(unapplied.len(), 1);
+		assert_eq!(unapplied[0].validator, 11);
+		assert_eq!(unapplied[0].own, 100);
+		assert!(unapplied[0].others.is_empty());
+
+		mock::start_active_era(4);
+		assert_eq!(balances(&11).0, 900);
+		assert_eq!(balances(&101).0, nominator_balance);
+	});
+}
+
 #[test]
 fn double_staking_should_fail() {
 	// should test (in the same order):
@@ -924,6 +1291,59 @@ fn session_and_eras_work_complex() {
 	});
 }
 
+#[test]
+fn era_start_block_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// the genesis era.
+		assert_eq!(Staking::era_start_block(0), Some(0));
+		assert_eq!(Staking::era_start_block(1), None);
+
+		for era in 1..4 {
+			mock::start_active_era(era);
+			assert_eq!(Staking::era_start_block(era), Some(System::block_number()));
+		}
+		assert_eq!(Staking::era_start_block(1), Some(15));
+		assert_eq!(Staking::era_start_block(2), Some(30));
+
+		// pruned with the rest of the era information.
+		mock::start_active_era(HistoryDepth::get() + 1);
+		assert_eq!(Staking::era_start_block(0), None);
+		assert_eq!(Staking::era_start_block(1), Some(15));
+	});
+}
+
+#[test]
+fn era_hooks_fire_at_era_rotation() {
+	ExtBuilder::default().build_and_execute(|| {
+		// era 0 started at genesis.
+		assert_eq!(EraHookCalls::get(), vec![EraHookCall::Start { era: 0, exposed: true }]);
+		EraHookCalls::set(vec![]);
+
+		mock::start_session(1);
+		mock::start_session(2);
+		assert!(EraHookCalls::get().is_empty());
+
+		// the ended era is rewarded before the hook fires, and the new era is exposed.
+		mock::start_active_era(1);
+		assert_eq!(
+			EraHookCalls::take(),
+			vec![
+				EraHookCall::End { era: 0, rewarded: true },
+				EraHookCall::Start { era: 1, exposed: true }
+			]
+		);
+
+		mock::start_active_era(2);
+		assert_eq!(
+			EraHookCalls::take(),
+			vec![
+				EraHookCall::End { era: 1, rewarded: true },
+				EraHookCall::Start { era: 2, exposed: true }
+			]
+		);
+	});
+}
+
 #[test]
 fn forcing_new_era_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -1051,6 +1471,99 @@ fn cannot_reserve_staked_balance() {
 	});
 }
 
+#[test]
+fn resolved_payee_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_eq!(Staking::payee(11.into()), Some(RewardDestination::Staked));
+		assert_eq!(Staking::resolved_payee(&11), Some(11));
+
+		<Payee<Test>>::insert(&11, RewardDestination::Stash);
+		assert_eq!(Staking::resolved_payee(&11), Some(11));
+
+		<Payee<Test>>::insert(&11, RewardDestination::Account(7));
+		assert_eq!(Staking::resolved_payee(&11), Some(7));
+
+		<Payee<Test>>::insert(&11, RewardDestination::None);
+		assert_eq!(Staking::resolved_payee(&11), None);
+
+		// not bonded.
+		assert_eq!(Staking::resolved_payee(&7), None);
+	});
+}
+
+#[test]
+fn delayed_payee_change_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = staking_events_since_last_call();
+
+		// without a delay, payee changes take effect immediately.
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Stash));
+		assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Stash));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::PayeeSet { stash: 11, payee: RewardDestination::Stash }]
+		);
+
+		// with a delay, the change is scheduled.
+		PayeeChangeDelay::set(Some(2));
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Account(42)));
+		assert_eq!(PendingPayee::<Test>::get(&11), Some((2, RewardDestination::Account(42))));
+		assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Stash));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::PayeeChangeScheduled {
+				stash: 11,
+				payee: RewardDestination::Account(42),
+				era: 2
+			}]
+		);
+
+		// not due yet.
+		mock::start_active_era(1);
+		run_to_block(System::block_number() + 1);
+		assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Stash));
+		assert!(PendingPayee::<Test>::contains_key(&11));
+
+		// applied at the start of the scheduled era.
+		let _ = staking_events_since_last_call();
+		mock::start_active_era(2);
+		assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Account(42)));
+		assert_eq!(PendingPayee::<Test>::get(&11), None);
+		assert!(staking_events_since_last_call()
+			.contains(&Event::PayeeSet { stash: 11, payee: RewardDestination::Account(42) }));
+	});
+}
+
+#[test]
+fn cancel_payee_change_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		PayeeChangeDelay::set(Some(1));
+		let initial_payee = Payee::<Test>::get(&11);
+
+		assert_noop!(
+			Staking::cancel_payee_change(RuntimeOrigin::signed(11)),
+			Error::<Test>::NoPendingPayeeChange
+		);
+
+		// a new change replaces the scheduled one.
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Account(42)));
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Account(43)));
+		assert_eq!(PendingPayee::<Test>::get(&11), Some((1, RewardDestination::Account(43))));
+
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::cancel_payee_change(RuntimeOrigin::signed(11)));
+		assert_eq!(PendingPayee::<Test>::get(&11), None);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::PayeeChangeCancelled { stash: 11 }]
+		);
+
+		// the payee is left untouched.
+		mock::start_active_era(1);
+		assert_eq!(Payee::<Test>::get(&11), initial_payee);
+	});
+}
+
 #[test]
 fn reward_destination_works() {
 	// Rewards go to the correct destination as determined in Payee
@@ -1165,17 +1678,131 @@ fn reward_destination_works() {
 }
 
 #[test]
-fn validator_payment_prefs_work() {
-	// Test that validator preferences are correctly honored
-	// Note: unstake threshold is being directly tested in slashing tests.
-	// This test will focus on validator payment.
-	ExtBuilder::default().build_and_execute(|| {
-		let commission = Perbill::from_percent(40);
-		<Validators<Test>>::insert(&11, ValidatorPrefs { commission, ..Default::default() });
-
-		// Reward stash so staked ratio doesn't change.
-		<Payee<Test>>::insert(&11, RewardDestination::Stash);
-		<Payee<Test>>::insert(&101, RewardDestination::Stash);
+fn pool_reward_destination_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// a no-op pool interface cannot route any rewards.
+		assert!(!<() as PoolRewardInterface<AccountId, Balance>>::can_route(1, &11));
+
+		// 11 is not a member of pool 1, so it cannot be selected as payee.
+		assert_noop!(
+			Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Pool(1)),
+			Error::<Test>::PoolPayeeUnavailable
+		);
+		let _ = Balances::make_free_balance_be(&61, 1000);
+		assert_noop!(
+			Staking::bond(RuntimeOrigin::signed(61), 500, RewardDestination::Pool(1)),
+			Error::<Test>::PoolPayeeUnavailable
+		);
+
+		PoolMembers::set(vec![(1, 11)]);
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Pool(1)));
+		assert_eq!(Staking::payee(11.into()), Some(RewardDestination::Pool(1)));
+
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		mock::start_active_era(1);
+		mock::make_all_reward_payment(0);
+
+		// the reward is paid into the stash, without being staked, and credited to the pool.
+		assert_eq!(Balances::free_balance(11), 1000 + total_payout_0);
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1000);
+		assert_eq!(PoolRewardCredits::get(), vec![(1, 11, total_payout_0)]);
+	});
+}
+
+#[test]
+fn split_reward_destination_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		let portion = Perbill::from_percent(40);
+		assert_ok!(Staking::set_payee(
+			RuntimeOrigin::signed(11),
+			RewardDestination::Split { account: 42, portion }
+		));
+
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		mock::start_active_era(1);
+		let issuance = Balances::total_issuance();
+		mock::make_all_reward_payment(0);
+
+		// `portion` of the reward is staked, the rest is paid into the account.
+		let staked = portion * total_payout_0;
+		assert_eq!(Balances::free_balance(11), 1000 + staked);
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1000 + staked);
+		assert_eq!(Staking::ledger(11.into()).unwrap().total, 1000 + staked);
+		assert_eq!(Balances::free_balance(42), total_payout_0 - staked);
+		// exactly the reward is minted.
+		assert_eq!(Balances::total_issuance(), issuance + total_payout_0);
+	});
+}
+
+#[test]
+fn split_reward_destination_of_whole_reward_is_staked() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// staking the whole reward is the same as `Staked`, the account is never paid.
+		assert_ok!(Staking::set_payee(
+			RuntimeOrigin::signed(11),
+			RewardDestination::Split { account: 42, portion: Perbill::one() }
+		));
+
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		mock::start_active_era(1);
+		mock::make_all_reward_payment(0);
+
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1000 + total_payout_0);
+		assert_eq!(Balances::free_balance(42), 0);
+	});
+}
+
+#[test]
+fn era_reward_mint_failure_skips_era_payout() {
+	ExtBuilder::default().build_and_execute(|| {
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		// the payout of era 0 cannot be minted on top of the total issuance.
+		EraPayoutOverride::set(Some((Balance::MAX, 1)));
+		let issuance = Balances::total_issuance();
+		let remainder = RewardRemainderUnbalanced::get();
+		let _ = staking_events_since_last_call();
+
+		mock::start_active_era(1);
+
+		let events = staking_events_since_last_call();
+		assert!(events.contains(&Event::EraRewardMintFailed { era: 0 }));
+		assert!(!events.iter().any(|e| matches!(e, Event::EraPaid { .. })));
+
+		// nothing of the era payout is applied.
+		assert!(!ErasValidatorReward::<Test>::contains_key(0));
+		assert_eq!(RewardRemainderUnbalanced::get(), remainder);
+		assert_eq!(Balances::total_issuance(), issuance);
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0),
+			Error::<Test>::InvalidEraToReward
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
+		// the next era is paid out as usual.
+		EraPayoutOverride::set(None);
+		mock::start_active_era(2);
+		assert!(ErasValidatorReward::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn validator_payment_prefs_work() {
+	// Test that validator preferences are correctly honored
+	// Note: unstake threshold is being directly tested in slashing tests.
+	// This test will focus on validator payment.
+	ExtBuilder::default().build_and_execute(|| {
+		let commission = Perbill::from_percent(40);
+		<Validators<Test>>::insert(&11, ValidatorPrefs { commission, ..Default::default() });
+
+		// Reward stash so staked ratio doesn't change.
+		<Payee<Test>>::insert(&11, RewardDestination::Stash);
+		<Payee<Test>>::insert(&101, RewardDestination::Stash);
 
 		mock::start_active_era(1);
 		mock::make_all_reward_payment(0);
@@ -1238,6 +1865,10 @@ fn bond_extra_works() {
 				legacy_claimed_rewards: bounded_vec![],
 			}
 		);
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::BondedExtra { stash: 11, amount: 100, new_active: 1100 }
+		);
 
 		// Call the bond_extra function with a large number, should handle it
 		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(11), Balance::max_value()));
@@ -1252,6 +1883,99 @@ fn bond_extra_works() {
 				legacy_claimed_rewards: bounded_vec![],
 			}
 		);
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::BondedExtra { stash: 11, amount: 1000000 - 1100, new_active: 1000000 }
+		);
+	});
+}
+
+#[test]
+fn bond_emits_staker_onboarded_once() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = Balances::make_free_balance_be(&1337, 10_000);
+		let _ = staking_events_since_last_call();
+
+		// the initial bond of a fresh stash onboards it.
+		assert_ok!(Staking::bond(RuntimeOrigin::signed(1337), 1_000, RewardDestination::Stash));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::StakerOnboarded { stash: 1337 },
+				Event::Bonded { stash: 1337, amount: 1_000 }
+			]
+		);
+
+		// bonding extra funds does not onboard it again.
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(1337), 500));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::BondedExtra { stash: 1337, amount: 500, new_active: 1_500 }]
+		);
+	});
+}
+
+#[test]
+fn bond_and_bond_extra_respect_max_ledger_total() {
+	ExtBuilder::default().build_and_execute(|| {
+		MaxLedgerTotal::set(Some(1100));
+		let _ = Balances::make_free_balance_be(&1337, 10_000);
+		let _ = Balances::make_free_balance_be(&11, 10_000);
+
+		// bonding above the cap is rejected.
+		assert_noop!(
+			Staking::bond(RuntimeOrigin::signed(1337), 1101, RewardDestination::Stash),
+			Error::<Test>::LedgerCapExceeded
+		);
+		assert_ok!(Staking::bond(RuntimeOrigin::signed(1337), 1100, RewardDestination::Stash));
+		assert_eq!(Staking::ledger(1337.into()).unwrap().total, 1100);
+
+		// 11 has 1000 bonded, so it can bond at most 100 extra.
+		assert_noop!(
+			Staking::bond_extra(RuntimeOrigin::signed(11), 101),
+			Error::<Test>::LedgerCapExceeded
+		);
+		// .. which also applies to the greedy bond of all the free balance.
+		assert_noop!(
+			Staking::bond_extra(RuntimeOrigin::signed(11), Balance::max_value()),
+			Error::<Test>::LedgerCapExceeded
+		);
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(11), 100));
+		assert_eq!(Staking::ledger(11.into()).unwrap().total, 1100);
+	});
+}
+
+#[test]
+fn max_ledger_total_pays_out_rewards_above_cap_to_stash() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		MaxLedgerTotal::set(Some(1010));
+		assert_eq!(Staking::payee(11.into()), Some(RewardDestination::Staked));
+		assert_eq!(Staking::ledger(11.into()).unwrap().total, 1000);
+		let initial_balance = Balances::free_balance(&11);
+
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		assert!(total_payout_0 > 10);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		mock::start_active_era(1);
+		mock::make_all_reward_payment(0);
+
+		// the full reward is paid out to the stash..
+		assert_eq_error_rate!(Balances::free_balance(&11), initial_balance + total_payout_0, 2);
+		// .. but it is only compounded up to the cap.
+		assert_eq!(Staking::ledger(11.into()).unwrap().total, 1010);
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1010);
+		assert_eq!(Balances::balance_locked(crate::STAKING_ID, &11), 1010);
+		// the part above the cap is reported as such.
+		let reward = staking_events()
+			.into_iter()
+			.find_map(|e| match e {
+				Event::Rewarded { stash: 11, amount, .. } => Some(amount),
+				_ => None,
+			})
+			.unwrap();
+		assert!(staking_events()
+			.contains(&Event::RewardNotCompounded { stash: 11, amount: reward - 10 }));
 	});
 }
 
@@ -1272,6 +1996,59 @@ fn bond_extra_controller_bad_state_works() {
 	})
 }
 
+#[test]
+fn bond_extra_from_escrow_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let escrow = 1337;
+
+		// rewards of 11 are routed to the escrow.
+		assert_ok!(Staking::set_payee(
+			RuntimeOrigin::signed(11),
+			RewardDestination::Account(escrow)
+		));
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+		mock::make_all_reward_payment(0);
+		let rewards = Balances::free_balance(escrow);
+		assert!(rewards > 0);
+		let amount = rewards / 2;
+
+		// the escrow has not approved 11 yet.
+		assert_noop!(
+			Staking::bond_extra_from_escrow(RuntimeOrigin::signed(11), amount, escrow),
+			Error::<Test>::EscrowNotApproved
+		);
+
+		assert_ok!(Staking::set_escrow_approval(RuntimeOrigin::signed(escrow), 11, true));
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::EscrowApprovalSet { escrow, stash: 11, approved: true }
+		);
+
+		let active = Staking::ledger(11.into()).unwrap().active;
+		assert_ok!(Staking::bond_extra_from_escrow(RuntimeOrigin::signed(11), amount, escrow));
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, active + amount);
+		assert_eq!(Balances::free_balance(escrow), rewards - amount);
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::BondedExtra { stash: 11, amount, new_active: active + amount }
+		);
+
+		// the approval is only for 11.
+		assert_noop!(
+			Staking::bond_extra_from_escrow(RuntimeOrigin::signed(21), amount, escrow),
+			Error::<Test>::EscrowNotApproved
+		);
+
+		// and can be revoked.
+		assert_ok!(Staking::set_escrow_approval(RuntimeOrigin::signed(escrow), 11, false));
+		assert_noop!(
+			Staking::bond_extra_from_escrow(RuntimeOrigin::signed(11), amount, escrow),
+			Error::<Test>::EscrowNotApproved
+		);
+	});
+}
+
 #[test]
 fn bond_extra_and_withdraw_unbonded_works() {
 	//
@@ -1364,6 +2141,13 @@ fn bond_extra_and_withdraw_unbonded_works() {
 				legacy_claimed_rewards: bounded_vec![],
 			},
 		);
+		// the event reports the active stake left in the ledger.
+		assert!(staking_events().contains(&Event::Unbonded {
+			stash: 11,
+			amount: 1000,
+			remaining_active: Staking::ledger(11.into()).unwrap().active,
+			withdraw_era: 2 + 3,
+		}));
 
 		// Attempting to free the balances now will fail. 2 eras need to pass.
 		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
@@ -1412,6 +2196,38 @@ fn bond_extra_and_withdraw_unbonded_works() {
 	})
 }
 
+#[test]
+fn withdraw_unbonded_refunds_weight_when_not_reaping() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 unbonds partially, 61 unbonds everything it has bonded.
+		bond(61, 100);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 500));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 100));
+		mock::start_active_era(BondingDuration::get());
+
+		let update_weight = <Test as Config>::WeightInfo::withdraw_unbonded_update(0);
+		let kill_weight = <Test as Config>::WeightInfo::withdraw_unbonded_kill(0);
+		assert!(update_weight.all_lt(kill_weight));
+
+		// the ledger of 11 is only updated, the difference to the kill weight is refunded.
+		let call = TestCall::Staking(StakingCall::withdraw_unbonded { num_slashing_spans: 0 });
+		let info = call.get_dispatch_info();
+		assert_eq!(info.weight, kill_weight);
+		let result = call.dispatch(RuntimeOrigin::signed(11));
+		assert_ok!(result);
+		assert!(Staking::ledger(11.into()).is_ok());
+		assert_eq!(extract_actual_weight(&result, &info), update_weight);
+
+		// 61 is reaped, which uses the full weight.
+		let call = TestCall::Staking(StakingCall::withdraw_unbonded { num_slashing_spans: 0 });
+		let info = call.get_dispatch_info();
+		let result = call.dispatch(RuntimeOrigin::signed(61));
+		assert_ok!(result);
+		assert!(Staking::ledger(61.into()).is_err());
+		assert_eq!(extract_actual_weight(&result, &info), kill_weight);
+	});
+}
+
 #[test]
 fn many_unbond_calls_should_work() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -1484,6 +2300,40 @@ fn auto_withdraw_may_not_unlock_all_chunks() {
 	})
 }
 
+#[test]
+fn withdraw_and_revalidate_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		MinValidatorBond::<Test>::put(500);
+		let prefs = ValidatorPrefs { commission: Perbill::from_percent(10), ..Default::default() };
+
+		// 11 stops validating and unbonds part of its stake.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 300));
+
+		mock::start_active_era(BondingDuration::get());
+		assert_ok!(Staking::withdraw_and_revalidate(RuntimeOrigin::signed(11), 0, prefs.clone()));
+
+		// the unbonded funds are withdrawn and 11 is validating again.
+		assert_eq!(Staking::ledger(11.into()).unwrap().total, 700);
+		assert_eq!(Validators::<Test>::get(11), prefs);
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::ValidatorPrefsSet { stash: 11, old_prefs: None, new_prefs: prefs.clone() }
+		);
+
+		// not enough active bond left to validate.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 300));
+
+		mock::start_active_era(2 * BondingDuration::get());
+		assert_ok!(Staking::withdraw_and_revalidate(RuntimeOrigin::signed(11), 0, prefs));
+
+		// funds are still withdrawn, but 11 does not validate.
+		assert_eq!(Staking::ledger(11.into()).unwrap().total, 400);
+		assert!(!Validators::<Test>::contains_key(11));
+	})
+}
+
 #[test]
 fn rebond_works() {
 	//
@@ -1531,6 +2381,12 @@ fn rebond_works() {
 				legacy_claimed_rewards: bounded_vec![],
 			}
 		);
+		assert!(staking_events().contains(&Event::Unbonded {
+			stash: 11,
+			amount: 900,
+			remaining_active: Staking::ledger(11.into()).unwrap().active,
+			withdraw_era: 2 + 3,
+		}));
 
 		// Re-bond all the funds unbonded.
 		Staking::rebond(RuntimeOrigin::signed(11), 900).unwrap();
@@ -1585,6 +2441,7 @@ fn rebond_works() {
 		);
 
 		// Unbond parts of the funds in stash.
+		let _ = staking_events_since_last_call();
 		Staking::unbond(RuntimeOrigin::signed(11), 300).unwrap();
 		Staking::unbond(RuntimeOrigin::signed(11), 300).unwrap();
 		Staking::unbond(RuntimeOrigin::signed(11), 300).unwrap();
@@ -1598,6 +2455,17 @@ fn rebond_works() {
 				legacy_claimed_rewards: bounded_vec![],
 			}
 		);
+		assert_eq!(
+			staking_events_since_last_call()
+				.into_iter()
+				.filter(|e| matches!(e, Event::Unbonded { .. }))
+				.collect::<Vec<_>>(),
+			vec![
+				Event::Unbonded { stash: 11, amount: 300, remaining_active: 700, withdraw_era: 5 },
+				Event::Unbonded { stash: 11, amount: 300, remaining_active: 400, withdraw_era: 5 },
+				Event::Unbonded { stash: 11, amount: 300, remaining_active: 100, withdraw_era: 5 },
+			]
+		);
 
 		// Re-bond part of the funds unbonded.
 		Staking::rebond(RuntimeOrigin::signed(11), 500).unwrap();
@@ -1768,6 +2636,136 @@ fn rebond_emits_right_value_in_event() {
 	});
 }
 
+#[test]
+fn rebond_chunk_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// unbond in three distinct eras.
+		for era in 0..3 {
+			mock::start_active_era(era);
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+		}
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap().unlocking,
+			vec![
+				UnlockChunk { value: 100, era: 3 },
+				UnlockChunk { value: 100, era: 4 },
+				UnlockChunk { value: 100, era: 5 }
+			]
+		);
+
+		assert_noop!(
+			Staking::rebond_chunk(RuntimeOrigin::signed(11), 6, 100),
+			Error::<Test>::NoUnlockChunk
+		);
+
+		// only the middle chunk is rebonded, at most its value.
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::rebond_chunk(RuntimeOrigin::signed(11), 4, 1000));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 800,
+				unlocking: bounded_vec![
+					UnlockChunk { value: 100, era: 3 },
+					UnlockChunk { value: 100, era: 5 }
+				],
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Bonded { stash: 11, amount: 100 }]
+		);
+
+		// the chunk is gone.
+		assert_noop!(
+			Staking::rebond_chunk(RuntimeOrigin::signed(11), 4, 100),
+			Error::<Test>::NoUnlockChunk
+		);
+
+		// part of the oldest chunk is rebonded.
+		assert_ok!(Staking::rebond_chunk(RuntimeOrigin::signed(11), 3, 40));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap().unlocking,
+			vec![UnlockChunk { value: 60, era: 3 }, UnlockChunk { value: 100, era: 5 }]
+		);
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 840);
+		assert_eq!(staking_events_since_last_call(), vec![Event::Bonded { stash: 11, amount: 40 }]);
+	});
+}
+
+#[test]
+fn bond_extra_prefer_unlocking_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// 11 has two unlocking chunks and 500 of free balance that is not bonded yet.
+		Balances::make_free_balance_be(&11, 1500);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+		mock::start_active_era(1);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+		let _ = staking_events_since_last_call();
+
+		// the unlocking chunks are consumed first, most recent first.
+		assert_ok!(Staking::bond_extra_prefer_unlocking(RuntimeOrigin::signed(11), 150));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 950,
+				unlocking: bounded_vec![UnlockChunk { value: 50, era: 3 }],
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Bonded { stash: 11, amount: 150 }]
+		);
+
+		// only the remainder is bonded from the free balance.
+		assert_ok!(Staking::bond_extra_prefer_unlocking(RuntimeOrigin::signed(11), 300));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1250,
+				active: 1250,
+				unlocking: bounded_vec![],
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Bonded { stash: 11, amount: 50 },
+				Event::BondedExtra { stash: 11, amount: 250, new_active: 1250 },
+			]
+		);
+	});
+}
+
+#[test]
+fn bond_extra_from_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 has 500 of free balance that is not bonded yet, 300 of which is reserved.
+		Balances::make_free_balance_be(&11, 1500);
+		assert_ok!(Balances::reserve(&11, 300));
+		assert_eq!(Balances::free_balance(11), 1200);
+
+		// the free balance is bonded up to what is available, like `bond_extra`.
+		assert_ok!(Staking::bond_extra_from(RuntimeOrigin::signed(11), FundsSource::Free, 1000));
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1200);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::BondedExtra { stash: 11, amount: 200, new_active: 1200 }]
+		);
+
+		// the reserved balance is left untouched.
+		assert_eq!(Balances::reserved_balance(11), 300);
+	});
+}
+
 #[test]
 fn max_staked_rewards_default_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -1931,6 +2929,49 @@ fn reap_stash_works() {
 		});
 }
 
+#[test]
+fn bonded_accounts_count_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let initial_count = Staking::bonded_accounts_count();
+		assert_eq!(initial_count as usize, Ledger::<Test>::iter().count());
+
+		// bonding increases the count, including for idle stashes.
+		bond(300, 1000);
+		assert!(Staking::is_chilled(&300));
+		assert_eq!(Staking::bonded_accounts_count(), initial_count + 1);
+
+		// a failed bond does not change it.
+		assert_noop!(
+			Staking::bond(RuntimeOrigin::signed(300), 1000, RewardDestination::Stash),
+			Error::<Test>::AlreadyBonded
+		);
+		assert_eq!(Staking::bonded_accounts_count(), initial_count + 1);
+
+		// withdrawing all of the bond reaps the stash and decreases the count.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(300), 1000));
+		mock::start_active_era(BondingDuration::get());
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(300), 0));
+		assert!(!<Ledger<Test>>::contains_key(&300));
+		assert_eq!(Staking::bonded_accounts_count(), initial_count);
+	});
+}
+
+#[test]
+fn bonded_accounts_count_decreases_on_reap() {
+	ExtBuilder::default()
+		.existential_deposit(10)
+		.balance_factor(10)
+		.build_and_execute(|| {
+			let initial_count = Staking::bonded_accounts_count();
+
+			// see `reap_stash_works`.
+			Ledger::<Test>::insert(11, StakingLedger::<Test>::new(11, 5));
+			assert_ok!(Staking::reap_stash(RuntimeOrigin::signed(20), 11, 0));
+
+			assert_eq!(Staking::bonded_accounts_count(), initial_count - 1);
+		});
+}
+
 #[test]
 fn reap_stash_works_with_existential_deposit_zero() {
 	ExtBuilder::default()
@@ -1969,6 +3010,53 @@ fn reap_stash_works_with_existential_deposit_zero() {
 		});
 }
 
+#[test]
+fn prune_slashing_spans_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given an unbonded stash with some old slashing spans.
+		assert!(!<Bonded<Test>>::contains_key(&1));
+		let mut spans = slashing::SlashingSpans::new(0);
+		assert!(spans.end_span(1));
+		assert!(spans.end_span(7));
+		<SlashingSpans<Test>>::insert(1, spans);
+		for i in 0..3 {
+			SpanSlash::<Test>::insert((1, i), slashing::SpanRecord::default());
+		}
+		assert_eq!(Staking::slashing_spans(&1).unwrap().iter().count(), 3);
+
+		// bonding window starts at era 7.
+		mock::start_active_era(10);
+		assert_eq!(active_era() - BondingDuration::get(), 7);
+
+		// cannot prune spans of a bonded stash.
+		assert_noop!(
+			Staking::prune_slashing_spans(RuntimeOrigin::signed(20), 11, 0),
+			Error::<Test>::AlreadyBonded
+		);
+
+		// the number of spans must be provided.
+		assert_noop!(
+			Staking::prune_slashing_spans(RuntimeOrigin::signed(20), 1, 2),
+			Error::<Test>::IncorrectSlashingSpans
+		);
+
+		// when
+		assert_ok!(Staking::prune_slashing_spans(RuntimeOrigin::signed(20), 1, 3));
+
+		// then only the span that ended before the window is gone.
+		assert_eq!(
+			Staking::slashing_spans(&1).unwrap().iter().collect::<Vec<_>>(),
+			vec![
+				slashing::SlashingSpan { index: 2, start: 8, length: None },
+				slashing::SlashingSpan { index: 1, start: 2, length: Some(6) },
+			],
+		);
+		assert!(!SpanSlash::<Test>::contains_key((1, 0)));
+		assert!(SpanSlash::<Test>::contains_key((1, 1)));
+		assert!(SpanSlash::<Test>::contains_key((1, 2)));
+	});
+}
+
 #[test]
 fn switching_roles() {
 	// Test that it should be possible to switch between roles (nominator, validator, idle) with
@@ -2102,6 +3190,27 @@ fn bond_with_no_staked_value() {
 		});
 }
 
+#[test]
+fn bond_respects_ed_buffer() {
+	ExtBuilder::default().existential_deposit(5).build_and_execute(|| {
+		BondEdBuffer::set(10);
+		let _ = Balances::make_free_balance_be(&1, 100);
+
+		// bonding exactly the existential deposit is not enough.
+		assert_noop!(
+			Staking::bond(RuntimeOrigin::signed(1), 5, RewardDestination::Stash),
+			Error::<Test>::InsufficientBond,
+		);
+		assert_noop!(
+			Staking::bond(RuntimeOrigin::signed(1), 14, RewardDestination::Stash),
+			Error::<Test>::InsufficientBond,
+		);
+
+		assert_ok!(Staking::bond(RuntimeOrigin::signed(1), 15, RewardDestination::Stash));
+		assert_eq!(Staking::ledger(1.into()).unwrap().active, 15);
+	});
+}
+
 #[test]
 fn bond_with_little_staked_value_bounded() {
 	ExtBuilder::default()
@@ -2416,6 +3525,74 @@ fn unbonded_balance_is_not_slashable() {
 	})
 }
 
+#[test]
+fn sessions_per_era_override_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(SessionsPerEra::get(), 3);
+		mock::start_active_era(1);
+		assert_eq!(Staking::eras_start_session_index(1), Some(3));
+
+		// only root can set the override.
+		assert_noop!(Staking::set_sessions_per_era(RuntimeOrigin::signed(11), Some(2)), BadOrigin);
+		assert_ok!(Staking::set_sessions_per_era(RuntimeOrigin::root(), Some(2)));
+		assert_eq!(*staking_events().last().unwrap(), Event::SessionsPerEraSet { value: Some(2) });
+
+		// the ongoing era keeps its length.
+		assert_eq!(Staking::sessions_per_era(), 3);
+		start_session(5);
+		assert_eq!(active_era(), 1);
+		assert_eq!(Staking::eras_start_session_index(2), Some(6));
+		start_session(6);
+		assert_eq!(active_era(), 2);
+
+		// the next era spans the new number of sessions.
+		assert_eq!(Staking::sessions_per_era(), 2);
+		start_session(8);
+		assert_eq!(active_era(), 3);
+		assert_eq!(Staking::eras_start_session_index(3), Some(8));
+
+		// the value is clamped.
+		assert_ok!(Staking::set_sessions_per_era(RuntimeOrigin::root(), Some(0)));
+		assert_eq!(*staking_events().last().unwrap(), Event::SessionsPerEraSet { value: Some(1) });
+
+		// removing the override falls back to `SessionsPerEra`.
+		assert_ok!(Staking::set_sessions_per_era(RuntimeOrigin::root(), None));
+		start_session(10);
+		assert_eq!(active_era(), 4);
+		assert_eq!(Staking::sessions_per_era(), 3);
+		start_session(13);
+		assert_eq!(active_era(), 5);
+		assert_eq!(Staking::eras_start_session_index(5), Some(13));
+	});
+}
+
+#[test]
+fn era_reward_points_reset_emitted_once_per_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = staking_events_since_last_call();
+
+		for era in 1..=4 {
+			let era_start_session = era * SessionsPerEra::get();
+			// not emitted when the era is planned, only when it starts.
+			start_session(era_start_session - 1);
+			assert_eq!(Staking::eras_start_session_index(era), Some(era_start_session));
+			assert!(!staking_events_since_last_call()
+				.iter()
+				.any(|e| matches!(e, Event::EraRewardPointsReset { .. })));
+
+			start_session(era_start_session);
+			assert_eq!(active_era(), era);
+			assert_eq!(
+				staking_events_since_last_call()
+					.into_iter()
+					.filter(|e| matches!(e, Event::EraRewardPointsReset { .. }))
+					.collect::<Vec<_>>(),
+				vec![Event::EraRewardPointsReset { era }]
+			);
+		}
+	});
+}
+
 #[test]
 fn era_is_always_same_length() {
 	// This ensures that the sessions is always of the same length if there is no forcing no
@@ -2510,6 +3687,34 @@ fn offence_deselects_validator_even_when_slash_is_zero() {
 		});
 }
 
+#[test]
+fn disabled_validators_works() {
+	ExtBuilder::default()
+		.validator_count(7)
+		.set_status(41, StakerStatus::Validator)
+		.set_status(51, StakerStatus::Validator)
+		.set_status(201, StakerStatus::Validator)
+		.set_status(202, StakerStatus::Validator)
+		.build_and_execute(|| {
+			assert!(Staking::disabled_validators().is_empty());
+
+			on_offence_now(
+				&[OffenceDetails {
+					offender: (11, Staking::eras_stakers(active_era(), &11)),
+					reporters: vec![],
+				}],
+				&[Perbill::from_percent(10)],
+			);
+
+			assert!(is_disabled(11));
+			assert_eq!(Staking::disabled_validators(), vec![11]);
+
+			// validators are re-enabled in the new era.
+			mock::start_active_era(1);
+			assert!(Staking::disabled_validators().is_empty());
+		});
+}
+
 #[test]
 fn slashing_performed_according_exposure() {
 	// This test checks that slashing is performed according the exposure (or more precisely,
@@ -2707,6 +3912,23 @@ fn invulnerables_are_not_slashed() {
 	});
 }
 
+#[test]
+fn is_invulnerable_works() {
+	ExtBuilder::default().invulnerables(vec![11]).build_and_execute(|| {
+		assert!(Staking::is_invulnerable(&11));
+		assert!(!Staking::is_invulnerable(&21));
+
+		// it follows the changes of the invulnerables.
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![21, 31]));
+		assert!(!Staking::is_invulnerable(&11));
+		assert!(Staking::is_invulnerable(&21));
+		assert!(Staking::is_invulnerable(&31));
+
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![]));
+		assert!(!Staking::is_invulnerable(&21));
+	});
+}
+
 #[test]
 fn dont_slash_if_fraction_is_zero() {
 	// Don't slash if the fraction is zero.
@@ -2974,6 +4196,12 @@ fn slashes_are_summed_across_spans() {
 
 		assert_eq!(get_span(21).iter().collect::<Vec<_>>(), expected_spans);
 		assert_eq!(Balances::free_balance(21), 1900);
+		assert!(staking_events_since_last_call().contains(&Event::Slashed {
+			staker: 21,
+			amount: 100,
+			slash_era: 3,
+			span_index: 0
+		}));
 
 		// 21 has been force-chilled. re-signal intent to validate.
 		Staking::validate(RuntimeOrigin::signed(21), Default::default()).unwrap();
@@ -2998,6 +4226,13 @@ fn slashes_are_summed_across_spans() {
 
 		assert_eq!(get_span(21).iter().collect::<Vec<_>>(), expected_spans);
 		assert_eq!(Balances::free_balance(21), 1810);
+		// the second slash is attributed to the span that started after the first one.
+		assert!(staking_events_since_last_call().contains(&Event::Slashed {
+			staker: 21,
+			amount: 90,
+			slash_era: 4,
+			span_index: 1
+		}));
 	});
 }
 
@@ -3048,16 +4283,129 @@ fn deferred_slashes_are_deferred() {
 		assert!(matches!(
 			staking_events_since_last_call().as_slice(),
 			&[
-				Event::SlashReported { validator: 11, slash_era: 1, .. },
-				Event::StakersElected,
+				Event::SlashReported { validator: 11, fraction, slash_era: 1 },
+				Event::StakersElected { .. },
 				..,
-				Event::Slashed { staker: 11, amount: 100 },
-				Event::Slashed { staker: 101, amount: 12 }
-			]
+				Event::Slashed { staker: 11, amount: 100, slash_era: 1, span_index: 0 },
+				Event::Slashed { staker: 101, amount: 12, slash_era: 1, span_index: 0 }
+			] if fraction == Perbill::from_percent(10)
 		));
 	})
 }
 
+#[test]
+fn offence_exemption_skips_slash() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		ExemptValidators::set(vec![11]);
+		let _ = staking_events_since_last_call();
+
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1000);
+		assert_eq!(Staking::ledger(21.into()).unwrap().active, 1000);
+
+		on_offence_now(
+			&[
+				OffenceDetails {
+					offender: (11, Staking::eras_stakers(active_era(), &11)),
+					reporters: vec![],
+				},
+				OffenceDetails {
+					offender: (21, Staking::eras_stakers(active_era(), &21)),
+					reporters: vec![],
+				},
+			],
+			&[Perbill::from_percent(10), Perbill::from_percent(10)],
+		);
+
+		// the exempt validator is not slashed, the other one is.
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1000);
+		assert_eq!(Staking::ledger(21.into()).unwrap().active, 900);
+
+		let events = staking_events_since_last_call();
+		assert!(events.contains(&Event::SlashExempted { validator: 11 }));
+		assert!(!events.contains(&Event::SlashExempted { validator: 21 }));
+		assert!(events.contains(&Event::SlashReported {
+			validator: 11,
+			fraction: Perbill::zero(),
+			slash_era: 1
+		}));
+		assert!(!events.iter().any(|e| matches!(e, Event::Slashed { staker: 11, .. })));
+		assert!(events
+			.iter()
+			.any(|e| matches!(e, Event::Slashed { staker: 21, amount: 100, .. })));
+	});
+}
+
+#[test]
+fn slash_defer_window_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// slashes are applied immediately.
+		assert_eq!(Staking::slash_defer_window(1), None);
+	});
+
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		let apply_at = Staking::slash_defer_window(active_era()).unwrap();
+		assert_eq!(apply_at, 4);
+
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+		let _ = staking_events_since_last_call();
+
+		mock::start_active_era(apply_at - 1);
+		assert!(!staking_events_since_last_call()
+			.iter()
+			.any(|e| matches!(e, Event::Slashed { .. })));
+
+		mock::start_active_era(apply_at);
+		assert!(staking_events_since_last_call().contains(&Event::Slashed {
+			staker: 11,
+			amount: 100,
+			slash_era: 1,
+			span_index: 0
+		}));
+	});
+}
+
+#[test]
+fn only_slashes_at_or_above_defer_threshold_are_deferred() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		SlashDeferThreshold::set(Some(Perbill::from_percent(50)));
+		mock::start_active_era(1);
+		let initial_balance_21 = Balances::free_balance(21);
+		assert_eq!(Balances::free_balance(11), 1000);
+
+		// a slash below the threshold is applied right away.
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+		assert_eq!(Balances::free_balance(11), 900);
+
+		// a slash at the threshold is deferred.
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (21, Staking::eras_stakers(active_era(), &21)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(50)],
+		);
+		assert_eq!(Balances::free_balance(21), initial_balance_21);
+		assert_eq!(UnappliedSlashes::<Test>::get(4).len(), 1);
+
+		mock::start_active_era(4);
+		assert_eq!(Balances::free_balance(21), initial_balance_21 - 500);
+	});
+}
+
 #[test]
 fn retroactive_deferred_slashes_two_eras_before() {
 	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
@@ -3082,11 +4430,11 @@ fn retroactive_deferred_slashes_two_eras_before() {
 		assert!(matches!(
 			staking_events_since_last_call().as_slice(),
 			&[
-				Event::SlashReported { validator: 11, slash_era: 1, .. },
+				Event::SlashReported { validator: 11, fraction, slash_era: 1 },
 				..,
-				Event::Slashed { staker: 11, amount: 100 },
-				Event::Slashed { staker: 101, amount: 12 }
-			]
+				Event::Slashed { staker: 11, amount: 100, slash_era: 1, span_index: 0 },
+				Event::Slashed { staker: 101, amount: 12, slash_era: 1, span_index: 0 }
+			] if fraction == Perbill::from_percent(10)
 		));
 	})
 }
@@ -3121,11 +4469,11 @@ fn retroactive_deferred_slashes_one_before() {
 		assert!(matches!(
 			staking_events_since_last_call().as_slice(),
 			&[
-				Event::SlashReported { validator: 11, slash_era: 2, .. },
+				Event::SlashReported { validator: 11, fraction, slash_era: 2 },
 				..,
-				Event::Slashed { staker: 11, amount: 100 },
-				Event::Slashed { staker: 101, amount: 12 }
-			]
+				Event::Slashed { staker: 11, amount: 100, slash_era: 2, span_index: 0 },
+				Event::Slashed { staker: 101, amount: 12, slash_era: 2, span_index: 0 }
+			] if fraction == Perbill::from_percent(10)
 		));
 
 		// their ledger has already been slashed.
@@ -3266,11 +4614,11 @@ fn remove_deferred() {
 		assert!(matches!(
 			staking_events_since_last_call().as_slice(),
 			&[
-				Event::SlashReported { validator: 11, slash_era: 1, .. },
+				Event::SlashReported { validator: 11, fraction, slash_era: 1 },
 				..,
-				Event::Slashed { staker: 11, amount: 50 },
-				Event::Slashed { staker: 101, amount: 7 }
-			]
+				Event::Slashed { staker: 11, amount: 50, slash_era: 1, span_index: 0 },
+				Event::Slashed { staker: 101, amount: 7, slash_era: 1, span_index: 0 }
+			] if fraction == Perbill::from_percent(15)
 		));
 
 		let slash_10 = Perbill::from_percent(10);
@@ -3385,15 +4733,24 @@ fn slash_kicks_validators_not_nominators_and_disables_nominator_for_kicked_valid
 			assert_eq!(
 				staking_events_since_last_call(),
 				vec![
-					Event::StakersElected,
-					Event::EraPaid { era_index: 0, validator_payout: 11075, remainder: 33225 },
+					Event::StakersElected {
+						count: Session::validators().len() as u32,
+						total_stake: ErasTotalStake::<Test>::get(1),
+					},
+					Event::EraPaid {
+						era_index: 0,
+						validator_payout: 11075,
+						remainder: 33225,
+						total_staked: ErasTotalStake::<Test>::get(0),
+					},
+					Event::EraRewardPointsReset { era: 1 },
 					Event::SlashReported {
 						validator: 11,
 						fraction: Perbill::from_percent(10),
 						slash_era: 1
 					},
-					Event::Slashed { staker: 11, amount: 100 },
-					Event::Slashed { staker: 101, amount: 12 },
+					Event::Slashed { staker: 11, amount: 100, slash_era: 1, span_index: 0 },
+					Event::Slashed { staker: 101, amount: 12, slash_era: 1, span_index: 0 },
 				]
 			);
 
@@ -3457,8 +4814,17 @@ fn non_slashable_offence_disables_validator() {
 			assert_eq!(
 				staking_events_since_last_call(),
 				vec![
-					Event::StakersElected,
-					Event::EraPaid { era_index: 0, validator_payout: 11075, remainder: 33225 },
+					Event::StakersElected {
+						count: Session::validators().len() as u32,
+						total_stake: ErasTotalStake::<Test>::get(1),
+					},
+					Event::EraPaid {
+						era_index: 0,
+						validator_payout: 11075,
+						remainder: 33225,
+						total_staked: ErasTotalStake::<Test>::get(0),
+					},
+					Event::EraRewardPointsReset { era: 1 },
 					Event::SlashReported {
 						validator: 11,
 						fraction: Perbill::from_percent(0),
@@ -3469,8 +4835,8 @@ fn non_slashable_offence_disables_validator() {
 						fraction: Perbill::from_percent(25),
 						slash_era: 1
 					},
-					Event::Slashed { staker: 21, amount: 250 },
-					Event::Slashed { staker: 101, amount: 94 }
+					Event::Slashed { staker: 21, amount: 250, slash_era: 1, span_index: 0 },
+					Event::Slashed { staker: 101, amount: 94, slash_era: 1, span_index: 0 }
 				]
 			);
 
@@ -3519,8 +4885,17 @@ fn slashing_independent_of_disabling_validator() {
 			assert_eq!(
 				staking_events_since_last_call(),
 				vec![
-					Event::StakersElected,
-					Event::EraPaid { era_index: 0, validator_payout: 11075, remainder: 33225 },
+					Event::StakersElected {
+						count: Session::validators().len() as u32,
+						total_stake: ErasTotalStake::<Test>::get(1),
+					},
+					Event::EraPaid {
+						era_index: 0,
+						validator_payout: 11075,
+						remainder: 33225,
+						total_staked: ErasTotalStake::<Test>::get(0),
+					},
+					Event::EraRewardPointsReset { era: 1 },
 					Event::SlashReported {
 						validator: 11,
 						fraction: Perbill::from_percent(0),
@@ -3531,8 +4906,8 @@ fn slashing_independent_of_disabling_validator() {
 						fraction: Perbill::from_percent(25),
 						slash_era: 1
 					},
-					Event::Slashed { staker: 21, amount: 250 },
-					Event::Slashed { staker: 101, amount: 94 }
+					Event::Slashed { staker: 21, amount: 250, slash_era: 1, span_index: 0 },
+					Event::Slashed { staker: 101, amount: 94, slash_era: 1, span_index: 0 }
 				]
 			);
 
@@ -3970,6 +5345,7 @@ fn test_multi_page_payout_stakers_by_page() {
 				..,
 				Event::Rewarded { stash: 1063, dest: RewardDestination::Stash, amount: 111 },
 				Event::Rewarded { stash: 1064, dest: RewardDestination::Stash, amount: 111 },
+				Event::RewardClaimedBy { caller: 1337, validator: 11, era: 1, page: 0 },
 			]
 		));
 
@@ -4408,6 +5784,35 @@ fn test_page_count_and_size() {
 	});
 }
 
+#[test]
+fn payout_emits_reward_claimed_by() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1), (21, 1)]);
+		mock::start_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		// claimed by a third party.
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+		let events = staking_events_since_last_call();
+		assert_eq!(
+			events.first(),
+			Some(&Event::PayoutStarted { era_index: 1, validator_stash: 11 })
+		);
+		assert_eq!(
+			events.last(),
+			Some(&Event::RewardClaimedBy { caller: 1337, validator: 11, era: 1, page: 0 })
+		);
+
+		// claimed by the validator itself.
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(21), 21, 1));
+		assert_eq!(
+			staking_events_since_last_call().last(),
+			Some(&Event::RewardClaimedBy { caller: 21, validator: 21, era: 1, page: 0 })
+		);
+	});
+}
+
 #[test]
 fn payout_stakers_handles_basic_errors() {
 	// Here we will test payouts handle all errors.
@@ -4719,6 +6124,49 @@ fn payout_stakers_handles_weight_refund() {
 	});
 }
 
+#[test]
+fn payout_multiple_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		Pallet::<Test>::reward_by_ids(vec![(11, 1), (21, 1)]);
+		mock::start_active_era(1);
+
+		// the page of 11 is already claimed.
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 0));
+		let _ = staking_events_since_last_call();
+
+		// the claimed page is skipped, while 21 and its nominator are paid out.
+		let call = TestCall::Staking(StakingCall::payout_multiple {
+			payouts: bounded_vec![(11, 0, 0), (21, 0, 0)],
+		});
+		let info = call.get_dispatch_info();
+		let result = call.dispatch(RuntimeOrigin::signed(1337));
+		assert_ok!(result);
+		assert_eq!(
+			extract_actual_weight(&result, &info),
+			<Test as Config>::WeightInfo::payout_stakers_alive_staked(0)
+				.saturating_add(<Test as Config>::WeightInfo::payout_stakers_alive_staked(1))
+		);
+
+		let events = staking_events_since_last_call();
+		assert_eq!(events[0], Event::PayoutStarted { era_index: 0, validator_stash: 21 });
+		assert!(matches!(events[1], Event::Rewarded { stash: 21, .. }));
+		assert!(matches!(events[2], Event::Rewarded { stash: 101, .. }));
+		assert_eq!(
+			events[3],
+			Event::RewardClaimedBy { caller: 1337, validator: 21, era: 0, page: 0 }
+		);
+		assert_eq!(events.len(), 4);
+		assert_eq!(Staking::claimed_rewards(0, &21), vec![0]);
+
+		// any other failure aborts the batch.
+		assert!(Staking::payout_multiple(
+			RuntimeOrigin::signed(1337),
+			bounded_vec![(11, 0, 0), (21, 1, 0)]
+		)
+		.is_err());
+	});
+}
+
 #[test]
 fn bond_during_era_does_not_populate_legacy_claimed_rewards() {
 	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
@@ -5489,6 +6937,15 @@ mod election_data_provider {
 	#[test]
 	fn estimate_next_election_works() {
 		ExtBuilder::default().session_per_era(5).period(5).build_and_execute(|| {
+			// the event of the election of the current era.
+			let stakers_elected = || {
+				let era = Staking::current_era().unwrap();
+				Event::StakersElected {
+					count: ErasStakersOverview::<Test>::iter_prefix(era).count() as u32,
+					total_stake: ErasTotalStake::<Test>::get(era),
+				}
+			};
+
 			// first session is always length 0.
 			for b in 1..20 {
 				run_to_block(b);
@@ -5499,7 +6956,7 @@ mod election_data_provider {
 			run_to_block(20);
 			assert_eq!(Staking::next_election_prediction(System::block_number()), 45);
 			assert_eq!(staking_events().len(), 1);
-			assert_eq!(*staking_events().last().unwrap(), Event::StakersElected);
+			assert_eq!(*staking_events().last().unwrap(), stakers_elected());
 
 			for b in 21..45 {
 				run_to_block(b);
@@ -5509,8 +6966,8 @@ mod election_data_provider {
 			// election
 			run_to_block(45);
 			assert_eq!(Staking::next_election_prediction(System::block_number()), 70);
-			assert_eq!(staking_events().len(), 3);
-			assert_eq!(*staking_events().last().unwrap(), Event::StakersElected);
+			assert_eq!(staking_events().len(), 4);
+			assert_eq!(*staking_events().last().unwrap(), stakers_elected());
 
 			Staking::force_no_eras(RuntimeOrigin::root()).unwrap();
 			assert_eq!(Staking::next_election_prediction(System::block_number()), u64::MAX);
@@ -5532,14 +6989,14 @@ mod election_data_provider {
 			MinimumValidatorCount::<Test>::put(2);
 			run_to_block(55);
 			assert_eq!(Staking::next_election_prediction(System::block_number()), 55 + 25);
-			assert_eq!(staking_events().len(), 10);
+			assert_eq!(staking_events().len(), 12);
 			assert_eq!(
 				*staking_events().last().unwrap(),
 				Event::ForceEra { mode: Forcing::NotForcing }
 			);
 			assert_eq!(
 				*staking_events().get(staking_events().len() - 2).unwrap(),
-				Event::StakersElected
+				stakers_elected()
 			);
 			// The new era has been planned, forcing is changed from `ForceNew` to `NotForcing`.
 			assert_eq!(ForceEra::<Test>::get(), Forcing::NotForcing);
@@ -5547,6 +7004,44 @@ mod election_data_provider {
 	}
 }
 
+#[test]
+fn force_era_transitions_emit_event() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = staking_events_since_last_call();
+
+		assert_ok!(Staking::force_no_eras(RuntimeOrigin::root()));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ForceEra { mode: Forcing::ForceNone }]
+		);
+
+		assert_ok!(Staking::force_new_era_always(RuntimeOrigin::root()));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ForceEra { mode: Forcing::ForceAlways }]
+		);
+
+		// forcing always is not reverted when a new era is planned.
+		advance_session();
+		assert_eq!(ForceEra::<Test>::get(), Forcing::ForceAlways);
+		assert!(!staking_events_since_last_call()
+			.iter()
+			.any(|e| matches!(e, Event::ForceEra { .. })));
+
+		assert_ok!(Staking::force_new_era(RuntimeOrigin::root()));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ForceEra { mode: Forcing::ForceNew }]
+		);
+
+		// `ForceNew` is reverted once the new era is planned.
+		advance_session();
+		assert_eq!(ForceEra::<Test>::get(), Forcing::NotForcing);
+		assert!(staking_events_since_last_call()
+			.contains(&Event::ForceEra { mode: Forcing::NotForcing }));
+	});
+}
+
 #[test]
 #[should_panic]
 fn count_check_works() {
@@ -5589,11 +7084,56 @@ fn check_payee_invariant2_works() {
 }
 
 #[test]
-fn min_bond_checks_work() {
-	ExtBuilder::default()
-		.existential_deposit(100)
-		.balance_factor(100)
-		.min_nominator_bond(1_000)
+#[should_panic = "called `Result::unwrap()` on an `Err` value: Other(\"wrong bonded count\")"]
+fn check_bonded_count_invariant_works() {
+	// `BondedCount` should match the number of ledgers. This test should panic as we verify that a
+	// bad state will panic due to the `try_state` checks in the `post_checks` in `mock`.
+	ExtBuilder::default().build_and_execute(|| {
+		BondedCount::<Test>::mutate(|count| *count += 1);
+	})
+}
+
+#[test]
+#[should_panic = "called `Result::unwrap()` on an `Err` value: Other(\"wrong total unlocking\")"]
+fn check_total_unlocking_invariant_works() {
+	// `TotalUnlocking` should match the unlocking balance of all ledgers. This test should panic
+	// as we verify that a bad state will panic due to the `try_state` checks in the `post_checks`
+	// in `mock`.
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+		TotalUnlocking::<Test>::mutate(|total| *total += 1);
+	})
+}
+
+#[test]
+fn total_unlocking_across_all_stakers_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_eq!(Staking::total_unlocking_across_all_stakers(), 0);
+
+		// unbonding adds to the total.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 300));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(21), 200));
+		assert_eq!(Staking::total_unlocking_across_all_stakers(), 500);
+
+		// rebonding removes from it.
+		assert_ok!(Staking::rebond(RuntimeOrigin::signed(11), 100));
+		assert_eq!(Staking::total_unlocking_across_all_stakers(), 400);
+
+		// and so does withdrawing.
+		mock::start_active_era(3);
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
+		assert_eq!(Staking::total_unlocking_across_all_stakers(), 200);
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(21), 0));
+		assert_eq!(Staking::total_unlocking_across_all_stakers(), 0);
+	});
+}
+
+#[test]
+fn min_bond_checks_work() {
+	ExtBuilder::default()
+		.existential_deposit(100)
+		.balance_factor(100)
+		.min_nominator_bond(1_000)
 		.min_validator_bond(1_500)
 		.build_and_execute(|| {
 			// 500 is not enough for any role
@@ -5640,6 +7180,345 @@ fn min_bond_checks_work() {
 		})
 }
 
+#[test]
+fn unbond_below_min_bond_auto_chills_if_enabled() {
+	ExtBuilder::default()
+		.existential_deposit(100)
+		.balance_factor(100)
+		.min_nominator_bond(1_000)
+		.min_validator_bond(1_500)
+		.build_and_execute(|| {
+			AutoChillOnUnbond::set(true);
+
+			assert_ok!(Staking::bond(RuntimeOrigin::signed(3), 1_500, RewardDestination::Stash));
+			assert_ok!(Staking::validate(RuntimeOrigin::signed(3), ValidatorPrefs::default()));
+			let _ = staking_events_since_last_call();
+
+			// unbonding below the validator min bond chills the validator first.
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(3), 500));
+			assert!(!Validators::<Test>::contains_key(3));
+			assert_eq!(Staking::status(&3).unwrap(), StakerStatus::Idle);
+			assert_eq!(Staking::ledger(3.into()).unwrap().active, 1_000);
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![
+					Event::Chilled { stash: 3 },
+					Event::Unbonded {
+						stash: 3,
+						amount: 500,
+						remaining_active: 1_000,
+						withdraw_era: 3
+					}
+				]
+			);
+
+			// unbonding without dropping below the min bond of the role does not chill.
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(3), vec![11]));
+			let _ = staking_events_since_last_call();
+			assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(3), 500));
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(3), 500));
+			assert!(Nominators::<Test>::contains_key(3));
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![
+					Event::BondedExtra { stash: 3, amount: 500, new_active: 1_500 },
+					Event::Unbonded {
+						stash: 3,
+						amount: 500,
+						remaining_active: 1_000,
+						withdraw_era: 3
+					}
+				]
+			);
+		})
+}
+
+#[test]
+fn unbond_warns_validator_dropping_below_elected_stake() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// both validators are exposed with their own stake only.
+		assert_eq!(Staking::minimum_stake_to_elect(), Some(1000));
+		let _ = staking_events_since_last_call();
+
+		// dropping below the lowest elected stake emits a warning.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+		assert!(Validators::<Test>::contains_key(11));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Unbonded { stash: 11, amount: 100, remaining_active: 900, withdraw_era: 3 },
+				Event::ValidatorStakeWarning {
+					stash: 11,
+					new_active: 900,
+					min_to_stay_elected: 1000
+				}
+			]
+		);
+
+		// the warning is only emitted once the threshold is crossed.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Unbonded {
+				stash: 11,
+				amount: 100,
+				remaining_active: 800,
+				withdraw_era: 3
+			}]
+		);
+
+		// nominators are not warned.
+		bond_nominator(61, 1000, vec![11]);
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 500));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Unbonded {
+				stash: 61,
+				amount: 500,
+				remaining_active: 500,
+				withdraw_era: 3
+			}]
+		);
+	});
+}
+
+#[test]
+fn unbond_below_min_evented_unbond_emits_no_event() {
+	ExtBuilder::default().build_and_execute(|| {
+		MinEventedUnbond::set(100);
+		bond_nominator(61, 1000, vec![11]);
+		let _ = staking_events_since_last_call();
+
+		// the chunk is created but no event is emitted.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 50));
+		assert_eq!(
+			Staking::ledger(61.into()).unwrap().unlocking,
+			vec![UnlockChunk { value: 50, era: 3 }]
+		);
+		assert!(staking_events_since_last_call().is_empty());
+
+		// unbonding at least the threshold emits the event.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 100));
+		assert_eq!(
+			Staking::ledger(61.into()).unwrap().unlocking,
+			vec![UnlockChunk { value: 150, era: 3 }]
+		);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Unbonded {
+				stash: 61,
+				amount: 100,
+				remaining_active: 850,
+				withdraw_era: 3
+			}]
+		);
+	});
+}
+
+#[test]
+fn reap_idle_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// disabled by default.
+		bond(61, 500);
+		assert_noop!(
+			Staking::reap_idle(RuntimeOrigin::signed(1337), 61, 0),
+			Error::<Test>::IdleReapDisabled
+		);
+		assert_eq!(IdleSince::<Test>::get(61), None);
+
+		IdleReapEras::set(Some(2));
+		bond(71, 500);
+		assert_eq!(IdleSince::<Test>::get(71), Some(0));
+		// validating or nominating is not idle.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(71), vec![11]));
+		assert_eq!(IdleSince::<Test>::get(71), None);
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(71)));
+		assert_eq!(IdleSince::<Test>::get(71), Some(0));
+
+		mock::start_active_era(1);
+		assert_noop!(
+			Staking::reap_idle(RuntimeOrigin::signed(1337), 71, 0),
+			Error::<Test>::NotIdleLongEnough
+		);
+
+		// 101 becomes idle later on.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		assert_eq!(IdleSince::<Test>::get(101), Some(1));
+
+		mock::start_active_era(2);
+		assert_noop!(
+			Staking::reap_idle(RuntimeOrigin::signed(1337), 101, 0),
+			Error::<Test>::NotIdleLongEnough
+		);
+
+		// 71 has been idle for long enough, it is fully unbonded.
+		assert_eq!(
+			Staking::reap_idle(RuntimeOrigin::signed(1337), 71, 0).unwrap().actual_weight,
+			Some(<Test as Config>::WeightInfo::unbond())
+		);
+		let ledger = Staking::ledger(71.into()).unwrap();
+		assert_eq!(ledger.active, 0);
+		assert_eq!(ledger.unlocking, vec![UnlockChunk { value: 500, era: 5 }]);
+
+		// and reaped after the bonding duration.
+		mock::start_active_era(5);
+		assert_eq!(
+			Staking::reap_idle(RuntimeOrigin::signed(1337), 71, 0).unwrap().actual_weight,
+			Some(<Test as Config>::WeightInfo::withdraw_unbonded_kill(0))
+		);
+		assert!(!<Bonded<Test>>::contains_key(71));
+		assert_eq!(IdleSince::<Test>::get(71), None);
+	});
+}
+
+#[test]
+fn reap_idle_skips_stashes_bonded_through_staking_interface() {
+	ExtBuilder::default().build_and_execute(|| {
+		IdleReapEras::set(Some(2));
+
+		// 71 is bonded and managed by another pallet.
+		assert_ok!(<Staking as sp_staking::StakingInterface>::bond(&71, 500, &72));
+		assert!(!ReapableStashes::<Test>::contains_key(71));
+		assert_eq!(IdleSince::<Test>::get(71), None);
+		assert_ok!(<Staking as sp_staking::StakingInterface>::nominate(&71, vec![11]));
+		assert_ok!(<Staking as sp_staking::StakingInterface>::chill(&71));
+		assert_eq!(IdleSince::<Test>::get(71), None);
+
+		mock::start_active_era(3);
+		assert_noop!(
+			Staking::reap_idle(RuntimeOrigin::signed(1337), 71, 0),
+			Error::<Test>::NotIdleLongEnough
+		);
+		assert_eq!(Staking::ledger(71.into()).unwrap().active, 500);
+	});
+}
+
+#[test]
+fn nominate_until_chills_expired_nominators() {
+	ExtBuilder::default().build_and_execute(|| {
+		// each block only inspects a single entry.
+		NominationExpiryScanLimit::set(1);
+
+		// the expiry must be in the future.
+		assert_noop!(
+			Staking::nominate_until(RuntimeOrigin::signed(101), vec![11], 0),
+			Error::<Test>::InvalidNominationExpiry
+		);
+
+		assert_ok!(Staking::nominate_until(RuntimeOrigin::signed(101), vec![11, 21], 2));
+		assert_ok!(Staking::nominate_until(RuntimeOrigin::signed(41), vec![11], 3));
+		assert_eq!(NominationExpiry::<Test>::get(101), Some(2));
+		assert_eq!(NominationExpiry::<Test>::get(41), Some(3));
+
+		mock::start_active_era(1);
+		assert!(Nominators::<Test>::contains_key(101));
+		assert!(Nominators::<Test>::contains_key(41));
+
+		// both entries are scanned in the first two blocks of era 2.
+		mock::start_active_era(2);
+		run_to_block(System::block_number() + 1);
+		assert!(!Nominators::<Test>::contains_key(101));
+		assert!(Nominators::<Test>::contains_key(41));
+		assert_eq!(NominationExpiry::<Test>::get(101), None);
+		assert!(staking_events().contains(&Event::Chilled { stash: 101 }));
+
+		// nominating without an expiry removes it.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(41), vec![11]));
+		assert_eq!(NominationExpiry::<Test>::get(41), None);
+
+		mock::start_active_era(3);
+		run_to_block(System::block_number() + 1);
+		assert!(Nominators::<Test>::contains_key(41));
+	});
+}
+
+#[test]
+fn auto_payout_pays_out_previous_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		AutoPayout::set(true);
+		// a single payout attempt per block, spreading the payout over several blocks.
+		MaxAutoPayoutsPerBlock::set(1);
+		let claimed = |validator| !ClaimedRewards::<Test>::get(1, validator).is_empty();
+
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1), (21, 1)]);
+		assert_eq!(AutoPayoutCursor::<Test>::get(), None);
+
+		// the first validator is paid out in the first block of era 2.
+		mock::start_active_era(2);
+		assert!(ErasValidatorReward::<Test>::get(1).unwrap() > 0);
+		assert!(claimed(11) ^ claimed(21));
+
+		// the rest of era 1 is paid out in the following blocks.
+		run_to_block(System::block_number() + 4);
+		assert!(claimed(11) && claimed(21));
+		assert_eq!(AutoPayoutCursor::<Test>::get(), None);
+		assert!(staking_events().iter().any(|e| matches!(e, Event::Rewarded { stash: 101, .. })));
+
+		// nothing is left to be claimed manually.
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1),
+			Error::<Test>::AlreadyClaimed
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+	});
+}
+
+#[test]
+fn chill_nominator_emits_nominations_cleared() {
+	ExtBuilder::default().build_and_execute(|| {
+		bond_nominator(61, 1000, vec![11, 21, 31]);
+		let _ = staking_events_since_last_call();
+
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(61)));
+		assert!(!Nominators::<Test>::contains_key(61));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Chilled { stash: 61 },
+				Event::ChilledWithRole { stash: 61, role: ChillRole::WasNominator },
+				Event::NominationsCleared { nominator: 61, had_targets: 3 }
+			]
+		);
+	});
+}
+
+#[test]
+fn chill_emits_role() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = staking_events_since_last_call();
+
+		// a validator.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Chilled { stash: 11 },
+				Event::ChilledWithRole { stash: 11, role: ChillRole::WasValidator }
+			]
+		);
+
+		// a nominator.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Chilled { stash: 101 },
+				Event::ChilledWithRole { stash: 101, role: ChillRole::WasNominator },
+				Event::NominationsCleared { nominator: 101, had_targets: 2 }
+			]
+		);
+
+		// already idle, only the role is reported.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ChilledWithRole { stash: 11, role: ChillRole::WasIdle }]
+		);
+	});
+}
+
 #[test]
 fn chill_other_works() {
 	ExtBuilder::default()
@@ -5789,58 +7668,226 @@ fn chill_other_works() {
 }
 
 #[test]
-fn capped_stakers_works() {
-	ExtBuilder::default().build_and_execute(|| {
-		let validator_count = Validators::<Test>::count();
-		assert_eq!(validator_count, 3);
-		let nominator_count = Nominators::<Test>::count();
-		assert_eq!(nominator_count, 1);
-
-		// Change the maximums
-		let max = 10;
-		assert_ok!(Staking::set_staking_configs(
-			RuntimeOrigin::root(),
-			ConfigOp::Set(10),
-			ConfigOp::Set(10),
-			ConfigOp::Set(max),
-			ConfigOp::Set(max),
-			ConfigOp::Remove,
-			ConfigOp::Remove,
-			ConfigOp::Noop,
-		));
+fn chill_other_without_count_limit_works() {
+	ExtBuilder::default()
+		.existential_deposit(100)
+		.balance_factor(100)
+		.min_nominator_bond(1_000)
+		.min_validator_bond(1_500)
+		.build_and_execute(|| {
+			ChillOtherRequiresCountLimit::set(false);
+			for (nominator, validator) in [(1, 2), (3, 4)] {
+				Balances::make_free_balance_be(&nominator, 100_000);
+				Balances::make_free_balance_be(&validator, 100_000);
+				assert_ok!(Staking::bond(
+					RuntimeOrigin::signed(nominator),
+					1000,
+					RewardDestination::Stash
+				));
+				assert_ok!(Staking::nominate(RuntimeOrigin::signed(nominator), vec![11]));
+				assert_ok!(Staking::bond(
+					RuntimeOrigin::signed(validator),
+					1500,
+					RewardDestination::Stash
+				));
+				assert_ok!(Staking::validate(
+					RuntimeOrigin::signed(validator),
+					ValidatorPrefs::default()
+				));
+			}
+			// 3 and 4 top up their bond.
+			assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(3), 1000));
+			assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(4), 1000));
 
-		// can create `max - validator_count` validators
-		let mut some_existing_validator = AccountId::default();
-		for i in 0..max - validator_count {
-			let (_, controller) = testing_utils::create_stash_controller::<Test>(
-				i + 10_000_000,
-				100,
-				RewardDestination::Stash,
-			)
-			.unwrap();
-			assert_ok!(Staking::validate(
-				RuntimeOrigin::signed(controller),
-				ValidatorPrefs::default()
+			// raise the minimum bonds, without any count limits.
+			assert_ok!(Staking::set_staking_configs(
+				RuntimeOrigin::root(),
+				ConfigOp::Set(1_500),
+				ConfigOp::Set(2_000),
+				ConfigOp::Remove,
+				ConfigOp::Remove,
+				ConfigOp::Remove,
+				ConfigOp::Remove,
+				ConfigOp::Noop,
 			));
-			some_existing_validator = controller;
-		}
-
-		// but no more
-		let (_, last_validator) =
-			testing_utils::create_stash_controller::<Test>(1337, 100, RewardDestination::Stash)
-				.unwrap();
 
-		assert_noop!(
-			Staking::validate(RuntimeOrigin::signed(last_validator), ValidatorPrefs::default()),
-			Error::<Test>::TooManyValidators,
-		);
+			// a threshold is still needed.
+			assert_noop!(
+				Staking::chill_other(RuntimeOrigin::signed(1337), 1),
+				Error::<Test>::CannotChillOther
+			);
 
-		// same with nominators
-		let mut some_existing_nominator = AccountId::default();
-		for i in 0..max - nominator_count {
-			let (_, controller) = testing_utils::create_stash_controller::<Test>(
-				i + 20_000_000,
-				100,
+			assert_ok!(Staking::set_staking_configs(
+				RuntimeOrigin::root(),
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Set(Percent::from_percent(75)),
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+			));
+
+			// stakers that meet the minimum bond cannot be chilled.
+			assert_noop!(
+				Staking::chill_other(RuntimeOrigin::signed(1337), 3),
+				Error::<Test>::CannotChillOther
+			);
+			assert_noop!(
+				Staking::chill_other(RuntimeOrigin::signed(1337), 4),
+				Error::<Test>::CannotChillOther
+			);
+
+			// under-bonded stakers can be chilled.
+			assert_ok!(Staking::chill_other(RuntimeOrigin::signed(1337), 1));
+			assert_ok!(Staking::chill_other(RuntimeOrigin::signed(1337), 2));
+			assert!(!Nominators::<Test>::contains_key(1));
+			assert!(!Validators::<Test>::contains_key(2));
+		})
+}
+
+#[test]
+fn chill_other_batch_works() {
+	ExtBuilder::default()
+		.existential_deposit(100)
+		.balance_factor(100)
+		.min_nominator_bond(1_000)
+		.min_validator_bond(1_500)
+		.build_and_execute(|| {
+			for (nominator, validator) in [(1, 2), (3, 4)] {
+				Balances::make_free_balance_be(&nominator, 100_000);
+				Balances::make_free_balance_be(&validator, 100_000);
+				assert_ok!(Staking::bond(
+					RuntimeOrigin::signed(nominator),
+					1000,
+					RewardDestination::Stash
+				));
+				assert_ok!(Staking::nominate(RuntimeOrigin::signed(nominator), vec![11]));
+				assert_ok!(Staking::bond(
+					RuntimeOrigin::signed(validator),
+					1500,
+					RewardDestination::Stash
+				));
+				assert_ok!(Staking::validate(
+					RuntimeOrigin::signed(validator),
+					ValidatorPrefs::default()
+				));
+			}
+			// 3 and 4 top up their bond.
+			assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(3), 1000));
+			assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(4), 1000));
+
+			// raise the minimum bonds and reach the limits.
+			assert_ok!(Staking::set_staking_configs(
+				RuntimeOrigin::root(),
+				ConfigOp::Set(1_500),
+				ConfigOp::Set(2_000),
+				ConfigOp::Set(Nominators::<Test>::count()),
+				ConfigOp::Set(Validators::<Test>::count()),
+				ConfigOp::Set(Percent::from_percent(75)),
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+			));
+			let _ = staking_events_since_last_call();
+
+			// 1 and 2 are under-bonded, 3 and 4 are not, and 5 is not bonded.
+			let call = TestCall::Staking(StakingCall::chill_other_batch {
+				targets: bounded_vec![1, 3, 2, 4, 5, 1],
+			});
+			let info = call.get_dispatch_info();
+			let result = call.dispatch(RuntimeOrigin::signed(1337));
+			assert_ok!(result);
+
+			assert!(!Nominators::<Test>::contains_key(1));
+			assert!(!Validators::<Test>::contains_key(2));
+			assert!(Nominators::<Test>::contains_key(3));
+			assert!(Validators::<Test>::contains_key(4));
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![
+					Event::Chilled { stash: 1 },
+					Event::Chilled { stash: 2 },
+					Event::ChilledBatch { count: 2 },
+					Event::ChilledBatchSkipped { stashes: vec![3, 4, 5, 1] }
+				]
+			);
+
+			// the weight reflects the actual chills.
+			assert_eq!(
+				extract_actual_weight(&result, &info),
+				<Test as Config>::WeightInfo::chill_other()
+					.saturating_mul(2)
+					.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(16))
+			);
+
+			// nothing left to chill.
+			assert_ok!(Staking::chill_other_batch(
+				RuntimeOrigin::signed(1337),
+				bounded_vec![1, 2, 3, 4]
+			));
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![
+					Event::ChilledBatch { count: 0 },
+					Event::ChilledBatchSkipped { stashes: vec![1, 2, 3, 4] }
+				]
+			);
+		})
+}
+
+#[test]
+fn capped_stakers_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let validator_count = Validators::<Test>::count();
+		assert_eq!(validator_count, 3);
+		let nominator_count = Nominators::<Test>::count();
+		assert_eq!(nominator_count, 1);
+
+		// Change the maximums
+		let max = 10;
+		assert_ok!(Staking::set_staking_configs(
+			RuntimeOrigin::root(),
+			ConfigOp::Set(10),
+			ConfigOp::Set(10),
+			ConfigOp::Set(max),
+			ConfigOp::Set(max),
+			ConfigOp::Remove,
+			ConfigOp::Remove,
+			ConfigOp::Noop,
+		));
+
+		// can create `max - validator_count` validators
+		let mut some_existing_validator = AccountId::default();
+		for i in 0..max - validator_count {
+			let (_, controller) = testing_utils::create_stash_controller::<Test>(
+				i + 10_000_000,
+				100,
+				RewardDestination::Stash,
+			)
+			.unwrap();
+			assert_ok!(Staking::validate(
+				RuntimeOrigin::signed(controller),
+				ValidatorPrefs::default()
+			));
+			some_existing_validator = controller;
+		}
+
+		// but no more
+		let (_, last_validator) =
+			testing_utils::create_stash_controller::<Test>(1337, 100, RewardDestination::Stash)
+				.unwrap();
+
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(last_validator), ValidatorPrefs::default()),
+			Error::<Test>::TooManyValidators,
+		);
+
+		// same with nominators
+		let mut some_existing_nominator = AccountId::default();
+		for i in 0..max - nominator_count {
+			let (_, controller) = testing_utils::create_stash_controller::<Test>(
+				i + 20_000_000,
+				100,
 				RewardDestination::Stash,
 			)
 			.unwrap();
@@ -5901,7 +7948,8 @@ fn min_commission_works() {
 			*staking_events().last().unwrap(),
 			Event::ValidatorPrefsSet {
 				stash: 11,
-				prefs: ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+				old_prefs: Some(ValidatorPrefs::default()),
+				new_prefs: ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
 			}
 		);
 
@@ -5935,9 +7983,223 @@ fn min_commission_works() {
 			RuntimeOrigin::signed(11),
 			ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
 		));
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::ValidatorPrefsSet {
+				stash: 11,
+				old_prefs: Some(ValidatorPrefs {
+					commission: Perbill::from_percent(10),
+					blocked: false
+				}),
+				new_prefs: ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
+			}
+		);
+	})
+}
+
+#[test]
+fn commission_change_rate_limit_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let prefs =
+			|percent| ValidatorPrefs { commission: Perbill::from_percent(percent), blocked: false };
+		let limit = CommissionChangeRate { max_increase: Perbill::from_percent(10), min_delay: 2 };
+
+		assert_noop!(
+			Staking::set_commission_change_rate(RuntimeOrigin::signed(11), Some(limit)),
+			BadOrigin
+		);
+		assert_ok!(Staking::set_commission_change_rate(RuntimeOrigin::root(), Some(limit)));
+		assert_eq!(CommissionChangeRateLimit::<Test>::get(), Some(limit));
+
+		// the commission cannot be raised by more than 10% at once.
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(11), prefs(100)),
+			Error::<Test>::CommissionIncreaseTooLarge
+		);
+
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(10)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::CommissionChanged {
+					stash: 11,
+					old: Perbill::zero(),
+					new: Perbill::from_percent(10)
+				},
+				Event::ValidatorPrefsSet {
+					stash: 11,
+					old_prefs: Some(prefs(0)),
+					new_prefs: prefs(10)
+				},
+			]
+		);
+		assert_eq!(LastCommissionUpdate::<Test>::get(11).unwrap().raised_in, Some(0));
+
+		// nor raised again before 2 eras have passed.
+		mock::start_active_era(1);
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(11), prefs(20)),
+			Error::<Test>::CommissionUpdateTooFrequent
+		);
+
+		// lowering it is always fine.
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(5)));
+		assert_eq!(LastCommissionUpdate::<Test>::get(11).unwrap().raised_in, Some(0));
+
+		mock::start_active_era(2);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(15)));
+		assert_eq!(LastCommissionUpdate::<Test>::get(11).unwrap().raised_in, Some(2));
+
+		// without a limit, the commission can be raised freely.
+		assert_ok!(Staking::set_commission_change_rate(RuntimeOrigin::root(), None));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(100)));
+	})
+}
+
+#[test]
+fn can_validate_checks_commission_change_rate() {
+	ExtBuilder::default().build_and_execute(|| {
+		let prefs =
+			|percent| ValidatorPrefs { commission: Perbill::from_percent(percent), blocked: false };
+		let limit = CommissionChangeRate { max_increase: Perbill::from_percent(10), min_delay: 2 };
+		assert_ok!(Staking::set_commission_change_rate(RuntimeOrigin::root(), Some(limit)));
+
+		// too large an increase is rejected by the preflight, like by `validate`.
+		assert_eq!(
+			Staking::can_validate(&11, &prefs(100)),
+			Err(Error::<Test>::CommissionIncreaseTooLarge)
+		);
+		assert_ok!(Staking::can_validate(&11, &prefs(10)));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(10)));
+
+		// and so is a new increase before `min_delay` has passed.
+		mock::start_active_era(1);
+		assert_eq!(
+			Staking::can_validate(&11, &prefs(20)),
+			Err(Error::<Test>::CommissionUpdateTooFrequent)
+		);
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(11), prefs(20)),
+			Error::<Test>::CommissionUpdateTooFrequent
+		);
+		// lowering it is fine.
+		assert_ok!(Staking::can_validate(&11, &prefs(5)));
+
+		mock::start_active_era(2);
+		assert_ok!(Staking::can_validate(&11, &prefs(20)));
+
+		// new validators are not limited.
+		assert_ok!(Staking::can_validate(&41, &prefs(100)));
+	})
+}
+
+#[test]
+fn commission_change_rate_limit_applies_after_chilling() {
+	ExtBuilder::default().build_and_execute(|| {
+		let prefs =
+			|percent| ValidatorPrefs { commission: Perbill::from_percent(percent), blocked: false };
+		let limit = CommissionChangeRate { max_increase: Perbill::from_percent(10), min_delay: 2 };
+		assert_ok!(Staking::set_commission_change_rate(RuntimeOrigin::root(), Some(limit)));
+
+		// 11 chills and comes back with a much higher commission.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert_eq!(
+			LastCommissionUpdate::<Test>::get(11),
+			Some(CommissionUpdate { commission: Perbill::zero(), raised_in: None })
+		);
+		assert_eq!(
+			Staking::can_validate(&11, &prefs(100)),
+			Err(Error::<Test>::CommissionIncreaseTooLarge)
+		);
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(11), prefs(100)),
+			Error::<Test>::CommissionIncreaseTooLarge
+		);
+
+		// the allowed increase counts towards `min_delay`, also across chilling.
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(10)));
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		mock::start_active_era(1);
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(11), prefs(20)),
+			Error::<Test>::CommissionUpdateTooFrequent
+		);
+		// nominating in between does not reset the commission either.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(11), vec![21]));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(11), prefs(20)),
+			Error::<Test>::CommissionUpdateTooFrequent
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(10)));
+
+		mock::start_active_era(2);
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), prefs(20)));
+		assert_eq!(
+			LastCommissionUpdate::<Test>::get(11),
+			Some(CommissionUpdate { commission: Perbill::from_percent(20), raised_in: Some(2) })
+		);
 	})
 }
 
+#[test]
+fn validator_nomination_filter_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 101 backs 11 with 125 and 21 with 375.
+		assert_eq!(Staking::eras_stakers(active_era(), &11).others.len(), 1);
+		let ledger_101 = Staking::ledger(101.into()).unwrap();
+
+		assert_noop!(
+			Staking::set_min_nominator_stake(RuntimeOrigin::signed(1337), Some(200)),
+			Error::<Test>::NotController
+		);
+		// nominators cannot set a filter.
+		assert_noop!(
+			Staking::set_min_nominator_stake(RuntimeOrigin::signed(101), Some(200)),
+			Error::<Test>::NotValidator
+		);
+		assert_ok!(Staking::set_min_nominator_stake(RuntimeOrigin::signed(11), Some(200)));
+		assert_eq!(ValidatorNominationFilter::<Test>::get(11), Some(200));
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::MinNominatorStakeSet { stash: 11, min: Some(200) }
+		);
+
+		// the current exposure is not affected.
+		assert_eq!(Staking::eras_stakers(active_era(), &11).total, 1125);
+
+		// from the next era onwards, 101 is dropped from the exposure of 11 only.
+		mock::start_active_era(1);
+		assert_eq!(
+			Staking::eras_stakers(active_era(), &11),
+			Exposure { total: 1000, own: 1000, others: vec![] },
+		);
+		assert_eq!(
+			Staking::eras_stakers(active_era(), &21),
+			Exposure {
+				total: 1375,
+				own: 1000,
+				others: vec![IndividualExposure { who: 101, value: 375 }]
+			},
+		);
+		assert_eq!(Staking::eras_total_stake(active_era()), 2375);
+
+		// the ledger and the nominations of 101 are untouched.
+		assert_eq!(Staking::ledger(101.into()).unwrap(), ledger_101);
+		assert_eq!(Staking::nominators(101).unwrap().targets, vec![11, 21]);
+
+		// removing the filter restores the backing.
+		assert_ok!(Staking::set_min_nominator_stake(RuntimeOrigin::signed(11), None));
+		assert_eq!(ValidatorNominationFilter::<Test>::get(11), None);
+		mock::start_active_era(2);
+		assert_eq!(
+			Staking::eras_stakers(active_era(), &11).others,
+			vec![IndividualExposure { who: 101, value: 125 }]
+		);
+	});
+}
+
 #[test]
 #[should_panic]
 #[cfg(debug_assertions)]
@@ -6465,6 +8727,43 @@ fn cannot_set_unsupported_validator_count() {
 	})
 }
 
+#[test]
+fn set_validator_count_and_min_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		MaxWinners::set(50);
+
+		assert_ok!(Staking::set_validator_count_and_min(RuntimeOrigin::root(), 30, 10));
+		assert_eq!(ValidatorCount::<Test>::get(), 30);
+		assert_eq!(MinimumValidatorCount::<Test>::get(), 10);
+
+		// both bounds are inclusive.
+		assert_ok!(Staking::set_validator_count_and_min(RuntimeOrigin::root(), 50, 50));
+		assert_eq!(ValidatorCount::<Test>::get(), 50);
+		assert_eq!(MinimumValidatorCount::<Test>::get(), 50);
+		assert_ok!(Staking::set_validator_count_and_min(RuntimeOrigin::root(), 1, 1));
+
+		// inconsistent inputs are rejected as a whole.
+		assert_noop!(
+			Staking::set_validator_count_and_min(RuntimeOrigin::root(), 20, 21),
+			Error::<Test>::InvalidMinimumValidatorCount,
+		);
+		assert_noop!(
+			Staking::set_validator_count_and_min(RuntimeOrigin::root(), 20, 0),
+			Error::<Test>::InvalidMinimumValidatorCount,
+		);
+		assert_noop!(
+			Staking::set_validator_count_and_min(RuntimeOrigin::root(), 51, 10),
+			Error::<Test>::TooManyValidators,
+		);
+		assert_noop!(
+			Staking::set_validator_count_and_min(RuntimeOrigin::signed(11), 20, 10),
+			BadOrigin,
+		);
+		assert_eq!(ValidatorCount::<Test>::get(), 1);
+		assert_eq!(MinimumValidatorCount::<Test>::get(), 1);
+	})
+}
+
 #[test]
 fn increase_validator_count_errors() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -6777,169 +9076,1088 @@ fn test_validator_exposure_is_backward_compatible_with_non_paged_rewards_payout(
 			assert!(<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&who, &1));
 		}
 
-		// case 2: exposure exist in ErasStakers and ErasStakersClipped (legacy).
-		// delete paged storage and add exposure to clipped storage
-		<ErasStakersPaged<Test>>::remove((1, 11, 0));
-		<ErasStakersPaged<Test>>::remove((1, 11, 1));
-		<ErasStakersOverview<Test>>::remove(1, 11);
+		// case 2: exposure exist in ErasStakers and ErasStakersClipped (legacy).
+		// delete paged storage and add exposure to clipped storage
+		<ErasStakersPaged<Test>>::remove((1, 11, 0));
+		<ErasStakersPaged<Test>>::remove((1, 11, 1));
+		<ErasStakersOverview<Test>>::remove(1, 11);
+
+		<ErasStakers<Test>>::insert(
+			1,
+			11,
+			Exposure {
+				total: total_exposure,
+				own: 1000,
+				others: expected_individual_exposures.clone(),
+			},
+		);
+		let mut clipped_exposure = expected_individual_exposures.clone();
+		clipped_exposure.sort_by(|a, b| b.who.cmp(&a.who));
+		clipped_exposure.truncate(10);
+		<ErasStakersClipped<Test>>::insert(
+			1,
+			11,
+			Exposure { total: total_exposure, own: 1000, others: clipped_exposure.clone() },
+		);
+
+		// verify `EraInfo` returns exposure from clipped storage
+		let actual_exposure_paged = EraInfo::<Test>::get_paged_exposure(1, &11, 0).unwrap();
+		assert_eq!(actual_exposure_paged.others(), &clipped_exposure);
+		assert_eq!(actual_exposure_paged.own(), 1000);
+		assert_eq!(actual_exposure_paged.exposure_metadata.page_count, 1);
+
+		let actual_exposure_full = EraInfo::<Test>::get_full_exposure(1, &11);
+		assert_eq!(actual_exposure_full.others, expected_individual_exposures);
+		assert_eq!(actual_exposure_full.own, 1000);
+		assert_eq!(actual_exposure_full.total, total_exposure);
+
+		// validator is exposed
+		assert!(<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&11, &1));
+		// nominators are exposed
+		for i in 10..15 {
+			let who: AccountId = 1000 + i;
+			assert!(<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&who, &1));
+		}
+
+		// for pages other than 0, clipped storage returns empty exposure
+		assert_eq!(EraInfo::<Test>::get_paged_exposure(1, &11, 1), None);
+		// page size is 1 for clipped storage
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 1);
+
+		// payout for page 0 works
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 0));
+		// payout for page 1 fails
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 1),
+			Error::<Test>::InvalidPage
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+	});
+}
+
+#[test]
+fn exposure_pages_works() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator_with_two_exposure_pages();
+
+		mock::start_active_era(1);
+
+		// multi-page exposure is returned in page order.
+		let pages = Staking::exposure_pages(1, &11);
+		assert_eq!(pages.len(), 2);
+		assert_eq!(pages[0], ErasStakersPaged::<Test>::get((1, 11, 0)).unwrap());
+		assert_eq!(pages[1], ErasStakersPaged::<Test>::get((1, 11, 1)).unwrap());
+		assert_eq!(pages.iter().map(|p| p.others.len()).sum::<usize>(), 15);
+
+		// no exposure.
+		assert!(Staking::exposure_pages(1, &21).is_empty());
+		assert!(Staking::exposure_pages(0, &11).is_empty());
+
+		// legacy exposure is returned as a single page.
+		<ErasStakersPaged<Test>>::remove((1, 11, 0));
+		<ErasStakersPaged<Test>>::remove((1, 11, 1));
+		<ErasStakersOverview<Test>>::remove(1, 11);
+		let clipped = Exposure {
+			total: 3000,
+			own: 1000,
+			others: vec![
+				IndividualExposure { who: 1000, value: 1000 },
+				IndividualExposure { who: 1001, value: 1000 },
+			],
+		};
+		<ErasStakersClipped<Test>>::insert(1, 11, clipped.clone());
+
+		assert_eq!(
+			Staking::exposure_pages(1, &11),
+			vec![ExposurePage { page_total: 3000, others: clipped.others }]
+		);
+	});
+}
+
+#[test]
+fn legacy_clipped_exposure_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+
+		// era 1 is backed by paged exposures only.
+		assert!(ErasStakersOverview::<Test>::get(1, &11).is_some());
+		assert_eq!(Staking::legacy_clipped_exposure(1, &11), None);
+
+		// clipped exposure is read directly from storage.
+		let clipped = Exposure {
+			total: 1250,
+			own: 1000,
+			others: vec![IndividualExposure { who: 101, value: 250 }],
+		};
+		<ErasStakersClipped<Test>>::insert(0, 11, clipped.clone());
+		assert_eq!(Staking::legacy_clipped_exposure(0, &11), Some(clipped));
+		assert_eq!(Staking::legacy_clipped_exposure(0, &21), None);
+	});
+}
+
+#[test]
+fn test_runtime_api_pending_rewards() {
+	ExtBuilder::default().build_and_execute(|| {
+		// GIVEN
+		let err_weight = <Test as Config>::WeightInfo::payout_stakers_alive_staked(0);
+		let stake = 100;
+
+		// validator with non-paged exposure, rewards marked in legacy claimed rewards.
+		let validator_one = 301;
+		// validator with non-paged exposure, rewards marked in paged claimed rewards.
+		let validator_two = 302;
+		// validator with paged exposure.
+		let validator_three = 303;
+
+		// Set staker
+		for v in validator_one..=validator_three {
+			let _ = Balances::make_free_balance_be(&v, stake);
+			assert_ok!(Staking::bond(RuntimeOrigin::signed(v), stake, RewardDestination::Staked));
+		}
+
+		// Add reward points
+		let reward = EraRewardPoints::<AccountId> {
+			total: 1,
+			individual: vec![(validator_one, 1), (validator_two, 1), (validator_three, 1)]
+				.into_iter()
+				.collect(),
+		};
+		ErasRewardPoints::<Test>::insert(0, reward);
+
+		// build exposure
+		let mut individual_exposures: Vec<IndividualExposure<AccountId, Balance>> = vec![];
+		for i in 0..=MaxExposurePageSize::get() {
+			individual_exposures.push(IndividualExposure { who: i.into(), value: stake });
+		}
+		let exposure = Exposure::<AccountId, Balance> {
+			total: stake * (MaxExposurePageSize::get() as Balance + 2),
+			own: stake,
+			others: individual_exposures,
+		};
+
+		// add non-paged exposure for one and two.
+		<ErasStakers<Test>>::insert(0, validator_one, exposure.clone());
+		<ErasStakers<Test>>::insert(0, validator_two, exposure.clone());
+		// add paged exposure for third validator
+		EraInfo::<Test>::set_exposure(0, &validator_three, exposure);
+
+		// add some reward to be distributed
+		ErasValidatorReward::<Test>::insert(0, 1000);
+
+		// mark rewards claimed for validator_one in legacy claimed rewards
+		<Ledger<Test>>::insert(
+			validator_one,
+			StakingLedgerInspect {
+				stash: validator_one,
+				total: stake,
+				active: stake,
+				unlocking: Default::default(),
+				legacy_claimed_rewards: bounded_vec![0],
+			},
+		);
+
+		// SCENARIO ONE: rewards already marked claimed in legacy storage.
+		// runtime api should return false for pending rewards for validator_one.
+		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_one));
+		// and if we try to pay, we get an error.
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_one, 0),
+			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
+		);
+
+		// SCENARIO TWO: non-paged exposure
+		// validator two has not claimed rewards, so pending rewards is true.
+		assert!(EraInfo::<Test>::pending_rewards(0, &validator_two));
+		// and payout works
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_two, 0));
+		// now pending rewards is false.
+		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_two));
+		// and payout fails
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_two, 0),
+			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
+		);
+
+		// SCENARIO THREE: validator with paged exposure (two pages).
+		// validator three has not claimed rewards, so pending rewards is true.
+		assert!(EraInfo::<Test>::pending_rewards(0, &validator_three));
+		// and payout works
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_three, 0));
+		// validator three has two pages of exposure, so pending rewards is still true.
+		assert!(EraInfo::<Test>::pending_rewards(0, &validator_three));
+		// payout again
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_three, 0));
+		// now pending rewards is false.
+		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_three));
+		// and payout fails
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_three, 0),
+			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
+		);
+
+		// for eras with no exposure, pending rewards is false.
+		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_one));
+		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_two));
+		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_three));
+	});
+}
+
+#[test]
+fn test_runtime_api_era_summary() {
+	ExtBuilder::default().build_and_execute(|| {
+		// eras that are not planned yet have no summary.
+		assert_eq!(Staking::api_era_summary(1), None);
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 10), (21, 5)]);
+		mock::start_active_era(1);
+
+		// the summary of a completed era matches the individual storage values.
+		let summary = Staking::api_era_summary(0).unwrap();
+		assert_eq!(summary.total_stake, ErasTotalStake::<Test>::get(0));
+		assert_eq!(summary.validator_reward, ErasValidatorReward::<Test>::get(0));
+		assert!(summary.validator_reward.is_some());
+		assert_eq!(summary.total_reward_points, ErasRewardPoints::<Test>::get(0).total);
+		assert_eq!(summary.total_reward_points, 15);
+		assert_eq!(
+			summary.validator_count,
+			ErasStakersOverview::<Test>::iter_prefix(0).count() as u32
+		);
+		assert_eq!(summary.validator_count, 2);
+
+		// the ongoing era has not been rewarded yet.
+		assert_eq!(Staking::api_era_summary(1).unwrap().validator_reward, None);
+
+		// eras outside of history depth have no summary.
+		mock::start_active_era(HistoryDepth::get() + 1);
+		assert_eq!(Staking::api_era_summary(0), None);
+		assert!(Staking::api_era_summary(1).is_some());
+	});
+}
+
+#[test]
+fn test_runtime_api_next_payout_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		// nothing to claim before any era has ended.
+		assert_eq!(Staking::api_next_payout_era(11), None);
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+
+		// eras 0 and 1 are unclaimed, so the oldest one is returned.
+		assert_eq!(Staking::api_next_payout_era(11), Some(0));
+		// 41 has no exposure.
+		assert_eq!(Staking::api_next_payout_era(41), None);
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0));
+		assert_eq!(Staking::api_next_payout_era(11), Some(1));
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+		assert_eq!(Staking::api_next_payout_era(11), None);
+	});
+}
+
+#[test]
+fn test_runtime_api_pending_payout_eras() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert!(Staking::api_pending_payout_eras(11).is_empty());
+
+		for era in 1..=3 {
+			Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+			mock::start_active_era(era);
+		}
+		assert_eq!(Staking::api_pending_payout_eras(11), vec![0, 1, 2]);
+		// 41 has no exposure.
+		assert!(Staking::api_pending_payout_eras(41).is_empty());
+
+		// era 1 is claimed, eras 0 and 2 are not.
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+		assert_eq!(Staking::api_pending_payout_eras(11), vec![0, 2]);
+
+		// the list shrinks as pages are claimed.
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0));
+		assert_eq!(Staking::api_pending_payout_eras(11), vec![2]);
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 2));
+		assert!(Staking::api_pending_payout_eras(11).is_empty());
+	});
+}
+
+#[test]
+fn test_runtime_api_validator_count_status() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Staking::api_validator_count_status(), (2, 2, 0, 100));
+
+		MaxWinners::set(50);
+		MinimumValidatorCount::<Test>::put(1);
+		assert_ok!(Staking::set_validator_count(RuntimeOrigin::root(), 3));
+
+		// the number of elected validators only changes with the next election.
+		assert_eq!(Staking::api_validator_count_status(), (2, 3, 1, 50));
+
+		mock::start_active_era(1);
+		assert_eq!(Staking::api_validator_count_status(), (3, 3, 1, 50));
+	});
+}
+
+#[test]
+fn test_runtime_api_exposure_page_of_nominator() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator_with_two_exposure_pages();
+		mock::start_active_era(1);
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 2);
+
+		let page_1 = ErasStakersPaged::<Test>::get((1, 11, 1)).unwrap();
+		let page_0 = ErasStakersPaged::<Test>::get((1, 11, 0)).unwrap();
+		assert_eq!(Staking::api_exposure_page_of_nominator(1, 11, page_1.others[0].who), Some(1));
+		assert_eq!(Staking::api_exposure_page_of_nominator(1, 11, page_0.others[0].who), Some(0));
+
+		// not exposed.
+		assert_eq!(Staking::api_exposure_page_of_nominator(1, 11, 1337), None);
+		assert_eq!(Staking::api_exposure_page_of_nominator(0, 11, page_1.others[0].who), None);
+	});
+}
+
+#[test]
+fn test_runtime_api_total_rewardable() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator_with_two_exposure_pages();
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		// nothing to claim before the era has ended.
+		assert_eq!(Staking::api_total_rewardable(1, 11), 0);
+
+		mock::start_active_era(2);
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 2);
+		let total_rewardable = Staking::api_total_rewardable(1, 11);
+		assert!(total_rewardable > 0);
+
+		let pre_issuance = Balances::total_issuance();
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+		let page_0_payout = Balances::total_issuance() - pre_issuance;
+		// only the unclaimed page remains.
+		assert_eq!(Staking::api_total_rewardable(1, 11), total_rewardable - page_0_payout);
+
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 1));
+		assert_eq!(Balances::total_issuance() - pre_issuance, total_rewardable);
+		assert_eq!(Staking::api_total_rewardable(1, 11), 0);
+	});
+}
+
+#[test]
+fn validator_reward_share_works() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator_with_two_exposure_pages();
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false }
+		));
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		// the reward is not known before the era has ended.
+		assert_eq!(Staking::validator_reward_share(1, &11, &1000), None);
+
+		mock::start_active_era(2);
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 2);
+		// not exposed to the validator.
+		assert_eq!(Staking::validator_reward_share(1, &11, &21), None);
+
+		let shares = (0..15)
+			.map(|i| Staking::validator_reward_share(1, &11, &(1000 + i)).unwrap())
+			.collect::<Vec<_>>();
+		assert!(shares.iter().all(|s| *s > 0));
+		let balances = (0..15).map(|i| Balances::total_balance(&(1000 + i))).collect::<Vec<_>>();
+
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 1));
+
+		for i in 0..15 {
+			let who = 1000 + i;
+			assert_eq!(Balances::total_balance(&who) - balances[i as usize], shares[i as usize]);
+			// already claimed.
+			assert_eq!(Staking::validator_reward_share(1, &11, &who), None);
+		}
+	});
+}
+
+#[test]
+fn total_exposed_stake_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// the genesis elected total.
+		assert_eq!(Staking::total_exposed_stake(0), Some(2500));
+		assert_eq!(Staking::total_exposed_stake(0), Some(Staking::eras_total_stake(0)));
+		// not planned yet.
+		assert_eq!(Staking::total_exposed_stake(1), None);
+
+		let history_depth = HistoryDepth::get();
+		mock::start_active_era(history_depth + 1);
+		// out of history depth.
+		assert_eq!(Staking::total_exposed_stake(0), None);
+		assert_eq!(Staking::total_exposed_stake(1), Some(2500));
+	});
+}
+
+#[test]
+fn current_total_stake_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// a genesis validator.
+		let genesis_total = Staking::current_total_stake(&21);
+		assert!(genesis_total > 0);
+		assert_eq!(genesis_total, Staking::eras_stakers(active_era(), &21).total);
+
+		// not exposed.
+		assert_eq!(Staking::current_total_stake(&101), 0);
+		assert_eq!(Staking::current_total_stake(&1337), 0);
+
+		// follows the active era.
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(21), 500));
+		assert_eq!(Staking::current_total_stake(&21), genesis_total);
+		mock::start_active_era(1);
+		assert!(Staking::current_total_stake(&21) > genesis_total);
+		assert_eq!(
+			Staking::current_total_stake(&21),
+			Staking::eras_stakers(active_era(), &21).total
+		);
+	});
+}
+
+#[test]
+fn active_era_start_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		// the start is recorded when the first block of the era is finalized.
+		assert_eq!(Staking::active_era_start(), None);
+
+		run_to_block(System::block_number() + 1);
+		assert_eq!(Staking::active_era_start(), Some(INIT_TIMESTAMP + 15 * BLOCK_TIME));
+		assert_eq!(Staking::active_era_start(), Staking::active_era().unwrap().start);
+
+		// no active era.
+		ActiveEra::<Test>::kill();
+		assert_eq!(Staking::active_era_start(), None);
+	});
+}
+
+#[test]
+fn is_exposed_in_any_recent_era_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 101 is only exposed in era 0.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		assert!(Staking::is_exposed_in_any_recent_era(&101));
+		assert!(Staking::is_exposed_in_any_recent_era(&11));
+
+		// a freshly bonded staker has never been exposed.
+		bond_nominator(1337, 1000, vec![11]);
+		assert!(!Staking::is_exposed_in_any_recent_era(&1337));
+
+		// exposed two eras ago.
+		mock::start_active_era(2);
+		assert!(!<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&101, &2));
+		assert!(Staking::is_exposed_in_any_recent_era(&101));
+		assert!(Staking::is_exposed_in_any_recent_era(&1337));
+
+		// era 0 is no longer within the bonding duration.
+		mock::start_active_era(1 + BondingDuration::get());
+		assert!(!Staking::is_exposed_in_any_recent_era(&101));
+		assert!(!Staking::is_exposed_in_any_recent_era(&2000));
+	});
+}
+
+#[test]
+fn staking_hold_amount_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// not bonded.
+		assert_eq!(Staking::staking_hold_amount(&2), 0);
+
+		// unrelated lock and reserve on the same account.
+		let _ = Balances::make_free_balance_be(&2, 2000);
+		Balances::set_lock(*b"other   ", &2, 300, WithdrawReasons::all());
+		assert_ok!(Balances::reserve(&2, 200));
+
+		bond(2, 1000);
+		assert_eq!(Staking::staking_hold_amount(&2), 1000);
+		assert_eq!(Staking::staking_hold_amount(&2), Staking::ledger(2.into()).unwrap().total);
+
+		// unbonding funds are still held until withdrawn.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(2), 400));
+		assert_eq!(Staking::staking_hold_amount(&2), 1000);
+		mock::start_active_era(BondingDuration::get());
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Staking::staking_hold_amount(&2), 600);
+
+		// the unrelated lock and reserve are untouched.
+		assert_eq!(Balances::balance_locked(*b"other   ", &2), 300);
+		assert_eq!(Balances::reserved_balance(&2), 200);
+	});
+}
+
+#[test]
+fn unlocking_total_at_era_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// not bonded.
+		assert_eq!(Staking::unlocking_total_at_era(&2, 5), 0);
+
+		// chunks maturing at eras 4, 5 and 6.
+		for (era, value) in [(1, 100), (2, 200), (3, 300)] {
+			mock::start_active_era(era);
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), value));
+		}
+		assert_eq!(
+			Staking::ledger(11.into())
+				.unwrap()
+				.unlocking
+				.iter()
+				.map(|chunk| chunk.era)
+				.collect::<Vec<_>>(),
+			vec![4, 5, 6]
+		);
+
+		assert_eq!(Staking::unlocking_total_at_era(&11, 3), 0);
+		assert_eq!(Staking::unlocking_total_at_era(&11, 4), 100);
+		assert_eq!(Staking::unlocking_total_at_era(&11, 5), 100 + 200);
+		assert_eq!(Staking::unlocking_total_at_era(&11, 6), 100 + 200 + 300);
+		assert_eq!(Staking::unlocking_total_at_era(&11, 100), 100 + 200 + 300);
+	});
+}
+
+#[test]
+fn nomination_submitted_in_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// not nominators.
+		assert_eq!(Staking::nomination_submitted_in(&11), None);
+		assert_eq!(Staking::nomination_submitted_in(&1337), None);
+
+		mock::start_active_era(3);
+		bond_nominator(61, 500, vec![11]);
+		assert_eq!(Staking::nomination_submitted_in(&61), Some(3));
+
+		// re-nominating updates the era.
+		mock::start_active_era(5);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(61), vec![21]));
+		assert_eq!(Staking::nomination_submitted_in(&61), Some(5));
+
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(61)));
+		assert_eq!(Staking::nomination_submitted_in(&61), None);
+	});
+}
+
+#[test]
+fn fast_unstake_eligible_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+
+		// 41 is bonded but has never been exposed.
+		assert!(Staking::fast_unstake_eligible(&41));
+		// exposed validators and nominators are not eligible.
+		assert!(!Staking::fast_unstake_eligible(&11));
+		assert!(!Staking::fast_unstake_eligible(&101));
+		// neither are accounts that are not bonded.
+		assert!(!Staking::fast_unstake_eligible(&42));
+
+		// a pending slash makes a stash not eligible.
+		UnappliedSlashes::<Test>::insert(1, vec![UnappliedSlash::default_from(41)]);
+		assert!(!Staking::fast_unstake_eligible(&41));
+		UnappliedSlashes::<Test>::remove(1);
+		assert!(Staking::fast_unstake_eligible(&41));
+
+		// 101 stops nominating, but is still exposed in the recent eras.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		mock::start_active_era(2);
+		assert!(!Staking::fast_unstake_eligible(&101));
+
+		// once it has not been exposed for `BondingDuration` eras, it is eligible.
+		mock::start_active_era(2 + BondingDuration::get());
+		assert!(Staking::fast_unstake_eligible(&101));
+	});
+}
+
+#[test]
+fn restrict_account_blocks_bond() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = Balances::make_free_balance_be(&300, 1000);
+		let _ = staking_events_since_last_call();
+
+		// only the admin origin can restrict accounts.
+		assert_noop!(Staking::restrict_account(RuntimeOrigin::signed(300), 300), BadOrigin);
+
+		assert_ok!(Staking::restrict_account(RuntimeOrigin::root(), 300));
+		assert!(Staking::is_restricted(&300));
+		assert_eq!(staking_events_since_last_call(), vec![Event::AccountRestricted { who: 300 }]);
+
+		assert_noop!(
+			Staking::bond(RuntimeOrigin::signed(300), 500, RewardDestination::Stash),
+			Error::<Test>::Restricted
+		);
+	});
+}
+
+#[test]
+fn unrestrict_account_reenables_bond() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = Balances::make_free_balance_be(&300, 1000);
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::restrict_account(RuntimeOrigin::root(), 300));
+
+		// only the admin origin can lift the restriction.
+		assert_noop!(Staking::unrestrict_account(RuntimeOrigin::signed(300), 300), BadOrigin);
+
+		assert_ok!(Staking::unrestrict_account(RuntimeOrigin::root(), 300));
+		assert!(!Staking::is_restricted(&300));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::AccountRestricted { who: 300 }, Event::AccountUnrestricted { who: 300 }]
+		);
+
+		assert_ok!(Staking::bond(RuntimeOrigin::signed(300), 500, RewardDestination::Stash));
+		assert_eq!(Staking::ledger(300.into()).unwrap().active, 500);
+	});
+}
+
+#[test]
+fn restricted_accounts_can_only_withdraw() {
+	ExtBuilder::default().build_and_execute(|| {
+		bond(300, 1000);
+		assert_ok!(Staking::restrict_account(RuntimeOrigin::root(), 300));
+
+		// cannot add to the bond.
+		assert_noop!(
+			Staking::bond_extra(RuntimeOrigin::signed(300), 100),
+			Error::<Test>::Restricted
+		);
+
+		// can unbond, but not rebond.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(300), 100));
+		assert_noop!(Staking::rebond(RuntimeOrigin::signed(300), 100), Error::<Test>::Restricted);
+
+		// and withdraw once the bonding duration has passed.
+		mock::start_active_era(BondingDuration::get());
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(300), 0));
+		assert_eq!(Staking::ledger(300.into()).unwrap().total, 900);
+	});
+}
+
+#[test]
+fn test_runtime_api_restricted_accounts() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert!(Staking::api_restricted_accounts().is_empty());
+
+		assert_ok!(Staking::restrict_account(RuntimeOrigin::root(), 300));
+		assert_ok!(Staking::restrict_account(RuntimeOrigin::root(), 301));
+		assert_eq_uvec!(Staking::api_restricted_accounts(), vec![300, 301]);
+
+		assert_ok!(Staking::unrestrict_account(RuntimeOrigin::root(), 300));
+		assert_eq!(Staking::api_restricted_accounts(), vec![301]);
+	});
+}
+
+#[test]
+fn test_runtime_api_approval_stake() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator(11, 1000);
+		bond_validator(21, 1000);
+		assert_eq!(Staking::api_approval_stake(11), 1000);
+
+		bond_nominator(101, 500, vec![11]);
+		bond_nominator(102, 300, vec![11, 21]);
+		// does not back 11.
+		bond_nominator(103, 700, vec![21]);
+
+		assert_eq!(Staking::api_approval_stake(11), 1000 + 500 + 300);
+		assert_eq!(Staking::api_approval_stake(21), 1000 + 300 + 700);
+
+		// chilled nominators do not count.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		assert_eq!(Staking::api_approval_stake(11), 1000 + 300);
+	});
+}
+
+#[test]
+fn test_runtime_api_validator_and_nominator_count() {
+	ExtBuilder::default().build_and_execute(|| {
+		// the genesis counts.
+		assert_eq!(Staking::api_validator_count(), 3);
+		assert_eq!(Staking::api_nominator_count(), 1);
+		assert_eq!(Staking::api_validator_count(), Validators::<Test>::count());
+		assert_eq!(Staking::api_nominator_count(), Nominators::<Test>::count());
+
+		bond_validator(61, 1000);
+		bond_nominator(71, 1000, vec![61]);
+		assert_eq!(Staking::api_validator_count(), 4);
+		assert_eq!(Staking::api_nominator_count(), 2);
+
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		assert_eq!(Staking::api_nominator_count(), 1);
+	});
+}
+
+#[test]
+fn test_runtime_api_slash_history() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(2);
+		assert!(Staking::api_slash_history(11).is_empty());
+
+		// 11 is slashed for offences in era 1 and era 2.
+		let own_1 = Staking::eras_stakers(1, &11).own;
+		let own_2 = Staking::eras_stakers(2, &11).own;
+		on_offence_in_era(
+			&[OffenceDetails { offender: (11, Staking::eras_stakers(1, &11)), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+			1,
+		);
+		on_offence_in_era(
+			&[OffenceDetails { offender: (11, Staking::eras_stakers(2, &11)), reporters: vec![] }],
+			&[Perbill::from_percent(20)],
+			2,
+		);
+
+		assert_eq!(
+			Staking::api_slash_history(11),
+			vec![(1, Perbill::from_percent(10) * own_1), (2, Perbill::from_percent(20) * own_2)]
+		);
+		// the nominator of 11 is slashed in both eras as well.
+		assert_eq!(
+			Staking::api_slash_history(101)
+				.into_iter()
+				.map(|(era, _)| era)
+				.collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+		assert!(Staking::api_slash_history(21).is_empty());
+
+		// the history is kept while the eras are bonded.
+		mock::start_active_era(1 + BondingDuration::get());
+		assert_eq!(Staking::api_slash_history(11).len(), 2);
+
+		// era 1 and then era 2 are pruned.
+		mock::start_active_era(2 + BondingDuration::get());
+		assert_eq!(Staking::api_slash_history(11), vec![(2, Perbill::from_percent(20) * own_2)]);
+		mock::start_active_era(3 + BondingDuration::get());
+		assert!(Staking::api_slash_history(11).is_empty());
+	});
+}
+
+#[test]
+fn test_runtime_api_slash_history_sums_validator_and_nominator_slashes() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(2);
+
+		// 21 is exposed as a nominator of 11 in era 1, and both offend in that era.
+		let mut exposure_11 = Staking::eras_stakers(1, &11);
+		exposure_11.others.push(IndividualExposure { who: 21, value: 100 });
+		exposure_11.total += 100;
+		let own_21 = Staking::eras_stakers(1, &21).own;
+		on_offence_in_era(
+			&[
+				OffenceDetails { offender: (11, exposure_11), reporters: vec![] },
+				OffenceDetails { offender: (21, Staking::eras_stakers(1, &21)), reporters: vec![] },
+			],
+			&[Perbill::from_percent(10), Perbill::from_percent(10)],
+			1,
+		);
+
+		let validator_slash = Perbill::from_percent(10) * own_21;
+		let nominator_slash = Perbill::from_percent(10) * 100;
+		assert_eq!(ValidatorSlashInEra::<Test>::get(1, &21).map(|(_, v)| v), Some(validator_slash));
+		assert_eq!(NominatorSlashInEra::<Test>::get(1, &21), Some(nominator_slash));
+		assert_eq!(Staking::api_slash_history(21), vec![(1, validator_slash + nominator_slash)]);
+	});
+}
+
+#[test]
+fn test_runtime_api_minimum_bond() {
+	ExtBuilder::default().build_and_execute(|| {
+		// the genesis minimums.
+		assert_eq!(Staking::api_minimum_bond(StakerRole::Validator), ExistentialDeposit::get());
+		assert_eq!(Staking::api_minimum_bond(StakerRole::Nominator), ExistentialDeposit::get());
+
+		MinValidatorBond::<Test>::put(1_000);
+		MinNominatorBond::<Test>::put(500);
+		assert_eq!(Staking::api_minimum_bond(StakerRole::Validator), 1_000);
+		assert_eq!(Staking::api_minimum_bond(StakerRole::Nominator), 500);
+	});
+}
+
+#[test]
+fn test_runtime_api_ledger_integrity() {
+	ExtBuilder::default().has_stakers(true).build_and_execute(|| {
+		setup_double_bonded_ledgers();
+		assert_eq!(Staking::api_ledger_integrity(333), Some(LedgerIntegrityState::Ok));
+		assert_eq!(Staking::api_ledger_integrity(444), Some(LedgerIntegrityState::Ok));
+
+		// 333 becomes corrupted.
+		set_controller_no_checks(&444);
+		assert_eq!(Staking::api_ledger_integrity(333), Some(LedgerIntegrityState::Corrupted));
+		assert_eq!(Staking::api_ledger_integrity(444), Some(LedgerIntegrityState::Ok));
+
+		// non-bonded accounts.
+		assert_eq!(Staking::api_ledger_integrity(1337), None);
+	});
+}
+
+#[test]
+fn test_runtime_api_exposure_delta() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_eq!(
+			ErasStakersOverview::<Test>::get(0, &11).map(|o| (o.total, o.own, o.nominator_count)),
+			Some((1000, 1000, 0))
+		);
+
+		// a new nominator backs 11 from the next era.
+		bond_nominator(61, 1000, vec![11]);
+		mock::start_active_era(1);
+
+		assert_eq!(
+			Staking::api_exposure_delta(0, 1, 11),
+			Some(ExposureDelta {
+				total_increase: 1000,
+				total_decrease: 0,
+				own_increase: 0,
+				own_decrease: 0,
+				nominator_count_change: 1,
+			})
+		);
+		// the other way around.
+		assert_eq!(
+			Staking::api_exposure_delta(1, 0, 11),
+			Some(ExposureDelta {
+				total_increase: 0,
+				total_decrease: 1000,
+				own_increase: 0,
+				own_decrease: 0,
+				nominator_count_change: -1,
+			})
+		);
+
+		// eras without exposure.
+		assert_eq!(Staking::api_exposure_delta(0, 2, 11), None);
+		assert_eq!(Staking::api_exposure_delta(0, 1, 1337), None);
+	});
+}
+
+#[test]
+fn test_runtime_api_apy_estimate() {
+	ExtBuilder::default()
+		.nominate(false)
+		.balance_factor(1000)
+		.build_and_execute(|| {
+			// an era lasts a day.
+			ExpectedBlockTime::set(86_400_000 / (SessionsPerEra::get() as u64 * Period::get()));
+
+			// no era is completed yet.
+			assert_eq!(Staking::api_apy_estimate(11), None);
+
+			mock::start_active_era(1);
+			assert_eq!(ErasStakersOverview::<Test>::get(0, &11).unwrap().total, 1_000_000);
+			ErasValidatorReward::<Test>::insert(0, 100);
+			ErasRewardPoints::<Test>::insert(
+				0,
+				EraRewardPoints { individual: vec![(11, 1)].into_iter().collect(), total: 1 },
+			);
+
+			// 100 per day for 1_000_000 staked, i.e. roughly 3.65% per year.
+			let apy = Staking::api_apy_estimate(11).unwrap();
+			assert!(apy > Perbill::from_percent(3) && apy < Perbill::from_percent(4));
+
+			// the commission is deducted.
+			ErasValidatorPrefs::<Test>::insert(
+				0,
+				11,
+				ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false },
+			);
+			let apy_after_commission = Staking::api_apy_estimate(11).unwrap();
+			assert!(apy_after_commission < apy);
+			assert!(apy_after_commission > Perbill::from_rational(32u32, 1000u32));
+
+			// an era lasts two days with twice as many sessions per era.
+			SessionsPerEraOverride::<Test>::put(2 * SessionsPerEra::get());
+			let apy_with_override = Staking::api_apy_estimate(11).unwrap();
+			assert!(apy_with_override < apy_after_commission);
+			assert!(apy_with_override > Perbill::from_rational(16u32, 1000u32));
+			SessionsPerEraOverride::<Test>::kill();
+
+			// not exposed in the last completed era.
+			assert_eq!(Staking::api_apy_estimate(1337), None);
+
+			// the era duration cannot be estimated.
+			ExpectedBlockTime::set(0);
+			assert_eq!(Staking::api_apy_estimate(11), None);
+		});
+}
+
+#[test]
+fn test_runtime_api_chilled_stakers() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 41 is bonded but idle.
+		assert_eq!(Staking::api_chilled_stakers(), vec![41]);
+
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(21)));
+		assert_eq_uvec!(Staking::api_chilled_stakers(), vec![21, 41]);
+
+		// active validators and nominators are not included.
+		for staker in [11, 31, 101] {
+			assert!(!Staking::api_chilled_stakers().contains(&staker));
+		}
+	});
+}
+
+#[test]
+fn test_runtime_api_pending_rewards_for() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert!(Staking::api_pending_rewards_for(101).is_empty());
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 1), (21, 2)]);
+		mock::start_active_era(1);
+
+		// 101 is exposed to both 11 and 21 in era 0.
+		let pending = Staking::api_pending_rewards_for(101);
+		assert_eq!(pending.len(), 1);
+		let (era, amount) = pending[0];
+		assert_eq!(era, 0);
+		assert!(amount > 0);
+
+		// claimed pages are excluded, and the amounts match what is actually paid out.
+		let balance = Balances::free_balance(101);
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0));
+		let paid_by_11 = Balances::free_balance(101) - balance;
+		assert_eq!(Staking::api_pending_rewards_for(101), vec![(0, amount - paid_by_11)]);
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 21, 0));
+		assert_eq!(Balances::free_balance(101) - balance, amount);
+		assert!(Staking::api_pending_rewards_for(101).is_empty());
+	});
+}
+
+#[test]
+fn test_runtime_api_validator_backers() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 3 pages of exposure
+		let nominator_count = 2 * MaxExposurePageSize::get() + 1;
+
+		for i in 0..nominator_count {
+			let stash = 10_000 + i as AccountId;
+			let balance = 10_000 + i as Balance;
+			Balances::make_free_balance_be(&stash, balance);
+			assert_ok!(Staking::bond(
+				RuntimeOrigin::signed(stash),
+				balance,
+				RewardDestination::Stash
+			));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(stash), vec![11]));
+		}
+		mock::start_active_era(1);
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 3);
+
+		// all the nominators of all pages are returned, along with 101.
+		let backers = Staking::api_validator_backers(1, 11);
+		assert_eq!(backers.len() as u32, nominator_count + 1);
+		for i in 0..nominator_count {
+			assert!(backers.contains(&(10_000 + i as AccountId, 10_000 + i as Balance)));
+		}
+		assert!(backers.iter().any(|(who, _)| *who == 101));
 
+		// legacy non-paged exposures are returned as well.
 		<ErasStakers<Test>>::insert(
 			1,
-			11,
+			61,
 			Exposure {
-				total: total_exposure,
+				total: 1500,
 				own: 1000,
-				others: expected_individual_exposures.clone(),
+				others: vec![IndividualExposure { who: 71, value: 500 }],
 			},
 		);
-		let mut clipped_exposure = expected_individual_exposures.clone();
-		clipped_exposure.sort_by(|a, b| b.who.cmp(&a.who));
-		clipped_exposure.truncate(10);
-		<ErasStakersClipped<Test>>::insert(
-			1,
-			11,
-			Exposure { total: total_exposure, own: 1000, others: clipped_exposure.clone() },
-		);
+		assert_eq!(Staking::api_validator_backers(1, 61), vec![(71, 500)]);
+		assert!(Staking::api_validator_backers(1, 1337).is_empty());
+	});
+}
 
-		// verify `EraInfo` returns exposure from clipped storage
-		let actual_exposure_paged = EraInfo::<Test>::get_paged_exposure(1, &11, 0).unwrap();
-		assert_eq!(actual_exposure_paged.others(), &clipped_exposure);
-		assert_eq!(actual_exposure_paged.own(), 1000);
-		assert_eq!(actual_exposure_paged.exposure_metadata.page_count, 1);
+#[test]
+fn test_runtime_api_election_candidates() {
+	ExtBuilder::default().validator_count(2).build_and_execute(|| {
+		// only 2 out of the 3 candidates are elected.
+		assert_eq_uvec!(Session::validators(), vec![11, 21]);
+		assert_eq_uvec!(Staking::api_election_candidates(), vec![11, 21, 31]);
 
-		let actual_exposure_full = EraInfo::<Test>::get_full_exposure(1, &11);
-		assert_eq!(actual_exposure_full.others, expected_individual_exposures);
-		assert_eq!(actual_exposure_full.own, 1000);
-		assert_eq!(actual_exposure_full.total, total_exposure);
+		// new candidates are included right away.
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(41), ValidatorPrefs::default()));
+		assert_eq_uvec!(Staking::api_election_candidates(), vec![11, 21, 31, 41]);
 
-		// validator is exposed
-		assert!(<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&11, &1));
-		// nominators are exposed
-		for i in 10..15 {
-			let who: AccountId = 1000 + i;
-			assert!(<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&who, &1));
-		}
+		// and chilled ones are removed.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(31)));
+		assert_eq_uvec!(Staking::api_election_candidates(), vec![11, 21, 41]);
+	});
+}
 
-		// for pages other than 0, clipped storage returns empty exposure
-		assert_eq!(EraInfo::<Test>::get_paged_exposure(1, &11, 1), None);
-		// page size is 1 for clipped storage
-		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 1);
+#[test]
+fn test_runtime_api_era_points_for() {
+	ExtBuilder::default().build_and_execute(|| {
+		Pallet::<Test>::reward_by_ids(vec![(11, 10), (21, 20)]);
 
-		// payout for page 0 works
-		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 0));
-		// payout for page 1 fails
-		assert_noop!(
-			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 1),
-			Error::<Test>::InvalidPage
-				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		// 31 has not been rewarded any points.
+		assert_eq!(
+			Staking::api_era_points_for(active_era(), vec![21, 31, 11]),
+			vec![(21, 20), (31, 0), (11, 10)]
 		);
+
+		// other eras have no points.
+		assert_eq!(Staking::api_era_points_for(active_era() + 1, vec![11]), vec![(11, 0)]);
 	});
 }
 
 #[test]
-fn test_runtime_api_pending_rewards() {
+fn test_runtime_api_full_exposure() {
 	ExtBuilder::default().build_and_execute(|| {
-		// GIVEN
-		let err_weight = <Test as Config>::WeightInfo::payout_stakers_alive_staked(0);
-		let stake = 100;
-
-		// validator with non-paged exposure, rewards marked in legacy claimed rewards.
-		let validator_one = 301;
-		// validator with non-paged exposure, rewards marked in paged claimed rewards.
-		let validator_two = 302;
-		// validator with paged exposure.
-		let validator_three = 303;
-
-		// Set staker
-		for v in validator_one..=validator_three {
-			let _ = Balances::make_free_balance_be(&v, stake);
-			assert_ok!(Staking::bond(RuntimeOrigin::signed(v), stake, RewardDestination::Staked));
-		}
+		assert_eq!(
+			Staking::api_full_exposure(0, 11),
+			Some(Exposure {
+				total: 1125,
+				own: 1000,
+				others: vec![IndividualExposure { who: 101, value: 125 }]
+			})
+		);
 
-		// Add reward points
-		let reward = EraRewardPoints::<AccountId> {
-			total: 1,
-			individual: vec![(validator_one, 1), (validator_two, 1), (validator_three, 1)]
-				.into_iter()
-				.collect(),
-		};
-		ErasRewardPoints::<Test>::insert(0, reward);
+		// 31 is not elected, and era 1 has not started yet.
+		assert_eq!(Staking::api_full_exposure(0, 31), None);
+		assert_eq!(Staking::api_full_exposure(1, 11), None);
 
-		// build exposure
-		let mut individual_exposures: Vec<IndividualExposure<AccountId, Balance>> = vec![];
-		for i in 0..=MaxExposurePageSize::get() {
-			individual_exposures.push(IndividualExposure { who: i.into(), value: stake });
-		}
-		let exposure = Exposure::<AccountId, Balance> {
-			total: stake * (MaxExposurePageSize::get() as Balance + 2),
-			own: stake,
-			others: individual_exposures,
+		// the legacy non-paged exposure is used if there is no paged exposure.
+		let legacy_exposure = Exposure {
+			total: 1500,
+			own: 1000,
+			others: vec![IndividualExposure { who: 101, value: 500 }],
 		};
+		ErasStakers::<Test>::insert(1, 11, legacy_exposure.clone());
+		assert_eq!(Staking::api_full_exposure(1, 11), Some(legacy_exposure));
+	});
+}
 
-		// add non-paged exposure for one and two.
-		<ErasStakers<Test>>::insert(0, validator_one, exposure.clone());
-		<ErasStakers<Test>>::insert(0, validator_two, exposure.clone());
-		// add paged exposure for third validator
-		EraInfo::<Test>::set_exposure(0, &validator_three, exposure);
-
-		// add some reward to be distributed
-		ErasValidatorReward::<Test>::insert(0, 1000);
-
-		// mark rewards claimed for validator_one in legacy claimed rewards
-		<Ledger<Test>>::insert(
-			validator_one,
-			StakingLedgerInspect {
-				stash: validator_one,
-				total: stake,
-				active: stake,
-				unlocking: Default::default(),
-				legacy_claimed_rewards: bounded_vec![0],
-			},
-		);
+#[test]
+fn test_runtime_api_reward_points_leaderboard() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert!(Staking::api_reward_points_leaderboard(0).is_empty());
 
-		// SCENARIO ONE: rewards already marked claimed in legacy storage.
-		// runtime api should return false for pending rewards for validator_one.
-		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_one));
-		// and if we try to pay, we get an error.
-		assert_noop!(
-			Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_one, 0),
-			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
-		);
+		Pallet::<Test>::reward_by_ids(vec![(11, 5), (21, 20), (31, 10)]);
+		assert_eq!(Staking::api_reward_points_leaderboard(0), vec![(21, 20), (31, 10), (11, 5)]);
+		// future eras.
+		assert!(Staking::api_reward_points_leaderboard(1).is_empty());
 
-		// SCENARIO TWO: non-paged exposure
-		// validator two has not claimed rewards, so pending rewards is true.
-		assert!(EraInfo::<Test>::pending_rewards(0, &validator_two));
-		// and payout works
-		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_two, 0));
-		// now pending rewards is false.
-		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_two));
-		// and payout fails
-		assert_noop!(
-			Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_two, 0),
-			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
-		);
+		// eras outside of the history depth.
+		mock::start_active_era(HistoryDepth::get() + 1);
+		assert!(Staking::api_reward_points_leaderboard(0).is_empty());
+	});
+}
 
-		// SCENARIO THREE: validator with paged exposure (two pages).
-		// validator three has not claimed rewards, so pending rewards is true.
-		assert!(EraInfo::<Test>::pending_rewards(0, &validator_three));
-		// and payout works
-		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_three, 0));
-		// validator three has two pages of exposure, so pending rewards is still true.
-		assert!(EraInfo::<Test>::pending_rewards(0, &validator_three));
-		// payout again
-		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_three, 0));
-		// now pending rewards is false.
-		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_three));
-		// and payout fails
-		assert_noop!(
-			Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_three, 0),
-			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
-		);
+#[test]
+fn is_chilled_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 41 is bonded but idle.
+		assert!(Staking::is_chilled(&41));
+		// validators, nominators and non-stakers are not chilled.
+		assert!(!Staking::is_chilled(&11));
+		assert!(!Staking::is_chilled(&101));
+		assert!(!Staking::is_chilled(&42));
 
-		// for eras with no exposure, pending rewards is false.
-		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_one));
-		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_two));
-		assert!(!EraInfo::<Test>::pending_rewards(0, &validator_three));
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert!(Staking::is_chilled(&11));
 	});
 }
 
@@ -7068,6 +10286,39 @@ mod staking_interface {
 			assert!(Staking::status(&42).is_err());
 		})
 	}
+
+	#[test]
+	fn status_batch() {
+		ExtBuilder::default().build_and_execute(|| {
+			let statuses = Staking::status_batch(&[42, 11, 101, 41, 10]);
+
+			assert_eq!(statuses.len(), 5);
+			assert!(statuses[0].is_err());
+			assert_eq!(statuses[1], Ok(StakerStatus::Validator));
+			assert_eq!(statuses[2], Ok(StakerStatus::Nominator(vec![11, 21])));
+			assert_eq!(statuses[3], Ok(StakerStatus::Idle));
+			assert!(statuses[4].is_err());
+
+			// empty input results in no statuses.
+			assert!(Staking::status_batch(&[]).is_empty());
+		})
+	}
+
+	#[test]
+	fn total_stake_in_era_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			mock::start_active_era(1);
+
+			let exposed: Balance =
+				ErasStakersOverview::<Test>::iter_prefix_values(0).map(|o| o.total).sum();
+			assert!(exposed > 0);
+			assert_eq!(Staking::total_stake_in_era(0), Some(exposed));
+
+			// eras that are not in the stored history yet.
+			assert_eq!(Staking::total_stake_in_era(2), None);
+			assert_eq!(Staking::total_stake_in_era(100), None);
+		})
+	}
 }
 
 mod staking_unchecked {
@@ -7144,6 +10395,77 @@ mod staking_unchecked {
 		})
 	}
 
+	#[test]
+	fn virtual_bond_extra_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(<Staking as StakingUnchecked>::virtual_bond(&10, 100, &15));
+
+			// 10 can bond extra more than its balance without getting locked.
+			assert_ok!(<Staking as StakingUnchecked>::virtual_bond_extra(&10, 1000));
+			assert_eq!(
+				<Staking as StakingInterface>::stake(&10),
+				Ok(Stake { total: 1100, active: 1100 })
+			);
+			assert_eq!(Balances::balance_locked(STAKING_ID, &10), 0);
+
+			// direct stakers are rejected.
+			assert_noop!(
+				<Staking as StakingUnchecked>::virtual_bond_extra(&11, 100),
+				Error::<Test>::NotVirtualStaker
+			);
+		})
+	}
+
+	#[test]
+	fn set_payee_of_virtual_staker_through_payee_control_origin_is_restricted() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(<Staking as StakingUnchecked>::virtual_bond(&10, 100, &15));
+			PayeeProxy::set(Some((1234, 10)));
+
+			// the rewards of a virtual staker cannot be paid back to itself.
+			for payee in [
+				RewardDestination::Staked,
+				RewardDestination::Stash,
+				RewardDestination::Account(10),
+			] {
+				assert_noop!(
+					Staking::set_payee(RuntimeOrigin::signed(1234), payee),
+					Error::<Test>::RewardDestinationRestricted
+				);
+			}
+
+			// any other account is fine.
+			assert_ok!(Staking::set_payee(
+				RuntimeOrigin::signed(1234),
+				RewardDestination::Account(16)
+			));
+			assert_eq!(Payee::<Test>::get(&10), Some(RewardDestination::Account(16)));
+		})
+	}
+
+	#[test]
+	fn split_payee_of_virtual_staker_is_restricted() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(<Staking as StakingUnchecked>::virtual_bond(&10, 100, &15));
+
+			// neither the account nor the staked portion can pay back to the stash.
+			for payee in [
+				RewardDestination::Split { account: 10, portion: Perbill::zero() },
+				RewardDestination::Split { account: 16, portion: Perbill::from_percent(1) },
+			] {
+				assert_noop!(
+					Staking::set_payee(RuntimeOrigin::signed(10), payee),
+					Error::<Test>::RewardDestinationRestricted
+				);
+			}
+
+			// paying everything out to another account is fine.
+			let payee = RewardDestination::Split { account: 16, portion: Perbill::zero() };
+			assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), payee));
+			assert_eq!(Payee::<Test>::get(&10), Some(payee));
+		})
+	}
+
 	#[test]
 	fn virtual_staker_cannot_pay_reward_to_self_account() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -7217,10 +10539,24 @@ mod staking_unchecked {
 			assert_ok!(Staking::bond(RuntimeOrigin::signed(200), 1000, RewardDestination::Staked));
 			assert_eq!(Balances::balance_locked(crate::STAKING_ID, &200), 1000);
 
-			// migrate them to virtual staker
-			<Staking as StakingUnchecked>::migrate_to_virtual_staker(&200);
+			// cannot migrate while the payee points back to the stash.
+			assert_noop!(
+				<Staking as StakingUnchecked>::migrate_to_virtual_staker(&200),
+				Error::<Test>::RewardDestinationRestricted
+			);
+			assert_ok!(Staking::set_payee(
+				RuntimeOrigin::signed(200),
+				RewardDestination::Account(200)
+			));
+			assert_noop!(
+				<Staking as StakingUnchecked>::migrate_to_virtual_staker(&200),
+				Error::<Test>::RewardDestinationRestricted
+			);
+
 			// payee needs to be updated to a non-stash account.
 			assert_ok!(<Staking as StakingInterface>::update_payee(&200, &201));
+			// migrate them to virtual staker
+			assert_ok!(<Staking as StakingUnchecked>::migrate_to_virtual_staker(&200));
 
 			// ensure the balance is not locked anymore
 			assert_eq!(Balances::balance_locked(crate::STAKING_ID, &200), 0);
@@ -7244,10 +10580,10 @@ mod staking_unchecked {
 				let initial_exposure = Staking::eras_stakers(active_era(), &11);
 				// 101 is a nominator for 11
 				assert_eq!(initial_exposure.others.first().unwrap().who, 101);
-				// make 101 a virtual nominator
-				<Staking as StakingUnchecked>::migrate_to_virtual_staker(&101);
 				// set payee different to self.
 				assert_ok!(<Staking as StakingInterface>::update_payee(&101, &102));
+				// make 101 a virtual nominator
+				assert_ok!(<Staking as StakingUnchecked>::migrate_to_virtual_staker(&101));
 
 				// cache values
 				let nominator_stake = Staking::ledger(101.into()).unwrap().active;
@@ -7319,10 +10655,10 @@ mod staking_unchecked {
 				let initial_exposure = Staking::eras_stakers(active_era(), &11);
 				// 101 is a nominator for 11
 				assert_eq!(initial_exposure.others.first().unwrap().who, 101);
-				// make 101 a virtual nominator
-				<Staking as StakingUnchecked>::migrate_to_virtual_staker(&101);
 				// set payee different to self.
 				assert_ok!(<Staking as StakingInterface>::update_payee(&101, &102));
+				// make 101 a virtual nominator
+				assert_ok!(<Staking as StakingUnchecked>::migrate_to_virtual_staker(&101));
 
 				// cache values
 				let validator_balance = Balances::free_balance(&11);
@@ -7376,7 +10712,8 @@ mod staking_unchecked {
 			// 333 is corrupted
 			assert_eq!(Staking::inspect_bond_state(&333).unwrap(), LedgerIntegrityState::Corrupted);
 			// migrate to virtual staker.
-			<Staking as StakingUnchecked>::migrate_to_virtual_staker(&333);
+			Payee::<Test>::insert(333, RewardDestination::Account(334));
+			assert_ok!(<Staking as StakingUnchecked>::migrate_to_virtual_staker(&333));
 
 			// recover the ledger won't work for virtual staker
 			assert_noop!(
@@ -7592,6 +10929,35 @@ mod ledger {
 		})
 	}
 
+	#[test]
+	fn set_payee_through_payee_control_origin_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Staked));
+
+			// 1234 is not a controller and is not authorized to act on behalf of one.
+			assert_noop!(
+				Staking::set_payee(RuntimeOrigin::signed(1234), RewardDestination::Stash),
+				Error::<Test>::NotController
+			);
+			assert_noop!(
+				Staking::set_payee(RuntimeOrigin::root(), RewardDestination::Stash),
+				BadOrigin
+			);
+
+			// authorize 1234 to set the payee on behalf of controller 11.
+			PayeeProxy::set(Some((1234, 11)));
+			assert_ok!(Staking::set_payee(
+				RuntimeOrigin::signed(1234),
+				RewardDestination::Account(1234)
+			));
+			assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Account(1234)));
+
+			// the controller itself can still set its payee.
+			assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Stash));
+			assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Stash));
+		})
+	}
+
 	#[test]
 	#[allow(deprecated)]
 	fn update_payee_migration_works() {
@@ -7706,6 +11072,8 @@ mod ledger {
 				);
 				Bonded::<Test>::insert(stash, ctlr);
 				Payee::<Test>::insert(stash, RewardDestination::Staked);
+				// keep the bonded count in sync, as `bond` would.
+				BondedCount::<Test>::mutate(|count| *count += 1);
 
 				controllers.push(ctlr);
 			}
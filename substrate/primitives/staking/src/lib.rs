@@ -73,6 +73,29 @@ pub enum StakerStatus<AccountId> {
 	Nominator(Vec<AccountId>),
 }
 
+/// A role that a staker can take.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum StakerRole {
+	/// Validating, i.e. authoring blocks.
+	Validator,
+	/// Nominating validators.
+	Nominator,
+}
+
+/// State of a ledger with regards with its data and metadata integrity.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum LedgerIntegrityState {
+	/// Ledger, bond and corresponding staking lock is OK.
+	Ok,
+	/// Ledger and/or bond is corrupted. This means that the bond has a ledger with a different
+	/// stash than the bonded stash.
+	Corrupted,
+	/// Ledger was corrupted and it has been killed.
+	CorruptedKilled,
+	/// Ledger and bond are OK, however the ledger's stash lock is out of sync.
+	LockCorrupted,
+}
+
 /// A struct that reflects stake that an account has in the staking system. Provides a set of
 /// methods to operate on it's properties. Aimed at making `StakingInterface` more concise.
 #[derive(RuntimeDebug, Clone, Copy, Eq, PartialEq, Default)]
@@ -277,9 +300,22 @@ pub trait StakingInterface {
 	/// Checks whether an account `staker` has been exposed in an era.
 	fn is_exposed_in_era(who: &Self::AccountId, era: &EraIndex) -> bool;
 
+	/// The total stake exposed in `era`, `None` if `era` is not in the stored history.
+	fn total_stake_in_era(era: EraIndex) -> Option<Self::Balance>;
+
 	/// Return the status of the given staker, `Err` if not staked at all.
 	fn status(who: &Self::AccountId) -> Result<StakerStatus<Self::AccountId>, DispatchError>;
 
+	/// Return the status of each of the given stakers, in the same order.
+	///
+	/// Each entry is `Err` if the corresponding account is not staked at all, as in
+	/// [`Self::status`].
+	fn status_batch(
+		accounts: &[Self::AccountId],
+	) -> Vec<Result<StakerStatus<Self::AccountId>, DispatchError>> {
+		accounts.iter().map(Self::status).collect()
+	}
+
 	/// Checks whether or not this is a validator account.
 	fn is_validator(who: &Self::AccountId) -> bool {
 		Self::status(who).map(|s| matches!(s, StakerStatus::Validator)).unwrap_or(false)
@@ -324,8 +360,9 @@ pub trait StakingInterface {
 pub trait StakingUnchecked: StakingInterface {
 	/// Migrate an existing staker to a virtual staker.
 	///
-	/// It would release all funds held by the implementation pallet.
-	fn migrate_to_virtual_staker(who: &Self::AccountId);
+	/// It would release all funds held by the implementation pallet. The current reward
+	/// destination of `who` must not pay the rewards back to `who` itself.
+	fn migrate_to_virtual_staker(who: &Self::AccountId) -> DispatchResult;
 
 	/// Book-keep a new bond for `keyless_who` without applying any locks (hence virtual).
 	///
@@ -338,6 +375,12 @@ pub trait StakingUnchecked: StakingInterface {
 		payee: &Self::AccountId,
 	) -> DispatchResult;
 
+	/// Book-keep `extra` on top of the existing virtual bond of `who`, without applying any locks.
+	///
+	/// Fails if `who` is not a virtual staker. As with [`Self::virtual_bond`], the caller is
+	/// responsible for ensuring the passed amount is locked and valid.
+	fn virtual_bond_extra(who: &Self::AccountId, extra: Self::Balance) -> DispatchResult;
+
 	/// Migrate a virtual staker to a direct staker.
 	///
 	/// Only used for testing.
@@ -463,6 +506,36 @@ pub struct PagedExposureMetadata<Balance: HasCompact + codec::MaxEncodedLen> {
 	pub page_count: Page,
 }
 
+/// An aggregated overview of a single era.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default, MaxEncodedLen)]
+pub struct EraSummary<Balance> {
+	/// The total balance staked in the era.
+	pub total_stake: Balance,
+	/// The total reward for validators and their nominators in the era, if the era has ended.
+	pub validator_reward: Option<Balance>,
+	/// The sum of the reward points earned by all validators in the era.
+	pub total_reward_points: u32,
+	/// The number of validators elected for the era.
+	pub validator_count: u32,
+}
+
+/// The change of the exposure of a validator from one era to another.
+///
+/// At most one of each `*_increase` and `*_decrease` pair is non-zero.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default, MaxEncodedLen)]
+pub struct ExposureDelta<Balance> {
+	/// The increase of the total balance backing the validator.
+	pub total_increase: Balance,
+	/// The decrease of the total balance backing the validator.
+	pub total_decrease: Balance,
+	/// The increase of the validator's own exposed stake.
+	pub own_increase: Balance,
+	/// The decrease of the validator's own exposed stake.
+	pub own_decrease: Balance,
+	/// The net change of the number of nominators backing the validator.
+	pub nominator_count_change: i32,
+}
+
 /// A type that belongs only in the context of an `Agent`.
 ///
 /// `Agent` is someone that manages delegated funds from [`Delegator`] accounts. It can